@@ -0,0 +1,20 @@
+use std::{error, fmt};
+
+pub mod adler32;
+pub mod crc;
+pub mod fletcher;
+
+#[derive(Debug)]
+pub enum ChecksumError {
+    InvalidWidthError,
+}
+
+impl fmt::Display for ChecksumError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChecksumError::InvalidWidthError => write!(f, "CRC width must be between 1 and 64"),
+        }
+    }
+}
+
+impl error::Error for ChecksumError {}
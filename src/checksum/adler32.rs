@@ -0,0 +1,40 @@
+use crate::hash::{Digest, Endianness, Message};
+
+const MOD_ADLER: u32 = 65521;
+
+pub struct Adler32;
+
+impl Adler32 {
+    pub fn checksum(&self, input: &Message) -> Digest {
+        let mut a: u32 = 1;
+        let mut b: u32 = 0;
+        for &byte in input.to_slice() {
+            a = (a + byte as u32) % MOD_ADLER;
+            b = (b + a) % MOD_ADLER;
+        }
+        Digest::from_u32(&[(b << 16) | a], Endianness::Big)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_adler32() {
+        let tests = [
+            ("", "00000001"),
+            ("a", "00620062"),
+            ("abc", "024d0127"),
+            ("Wikipedia", "11e60398"),
+            ("message digest", "29750586"),
+        ];
+
+        for (message, expected) in tests {
+            assert_eq!(
+                Adler32.checksum(&Message::from_string(message)).to_hex(),
+                expected
+            );
+        }
+    }
+}
@@ -0,0 +1,252 @@
+use crate::checksum::ChecksumError;
+use crate::hash::{Digest, Endianness, Message};
+
+fn reflect(value: u64, width: u32) -> u64 {
+    value.reverse_bits() >> (64 - width)
+}
+
+/*
+A CRC described by the usual Rocksoft model parameters. The register is
+processed one bit at a time so that any width between 1 and 64 works with
+the same code. Reflected CRCs shift the register to the right with the
+reflected polynomial, which makes the register hold the reflected result
+directly.
+*/
+pub struct CRC {
+    width: u32,
+    poly: u64,
+    init: u64,
+    reflect: bool,
+    xorout: u64,
+}
+
+impl CRC {
+    pub fn new(
+        width: u32,
+        poly: u64,
+        init: u64,
+        reflect: bool,
+        xorout: u64,
+    ) -> Result<Self, ChecksumError> {
+        if width == 0 || width > 64 {
+            return Err(ChecksumError::InvalidWidthError);
+        }
+        Ok(Self {
+            width,
+            poly,
+            init,
+            reflect,
+            xorout,
+        })
+    }
+
+    fn mask(&self) -> u64 {
+        u64::MAX >> (64 - self.width)
+    }
+
+    pub fn checksum(&self, input: &Message) -> Digest {
+        let mask: u64 = self.mask();
+        let mut register: u64;
+
+        if self.reflect {
+            let poly: u64 = reflect(self.poly & mask, self.width);
+            register = reflect(self.init & mask, self.width);
+            for &byte in input.to_slice() {
+                for bit in 0..8 {
+                    register ^= ((byte >> bit) & 1) as u64;
+                    register = match register & 1 {
+                        1 => (register >> 1) ^ poly,
+                        _ => register >> 1,
+                    };
+                }
+            }
+        } else {
+            let top_bit: u64 = 1 << (self.width - 1);
+            register = self.init & mask;
+            for &byte in input.to_slice() {
+                for bit in (0..8).rev() {
+                    register ^= (((byte >> bit) & 1) as u64) << (self.width - 1);
+                    register = match register & top_bit {
+                        0 => register << 1,
+                        _ => (register << 1) ^ self.poly,
+                    } & mask;
+                }
+            }
+        }
+        register = (register ^ self.xorout) & mask;
+
+        let num_bytes: usize = self.width.div_ceil(8) as usize;
+        Digest::from_u64_range(&[register], Endianness::Big, 8 - num_bytes..8).unwrap()
+    }
+}
+
+const CRC32_PARAMS: CRC = CRC {
+    width: 32,
+    poly: 0x04c11db7,
+    init: 0xffffffff,
+    reflect: true,
+    xorout: 0xffffffff,
+};
+
+const CRC32C_PARAMS: CRC = CRC {
+    width: 32,
+    poly: 0x1edc6f41,
+    init: 0xffffffff,
+    reflect: true,
+    xorout: 0xffffffff,
+};
+
+const CRC32_BZIP2_PARAMS: CRC = CRC {
+    width: 32,
+    poly: 0x04c11db7,
+    init: 0xffffffff,
+    reflect: false,
+    xorout: 0xffffffff,
+};
+
+const CRC32_MPEG2_PARAMS: CRC = CRC {
+    width: 32,
+    poly: 0x04c11db7,
+    init: 0xffffffff,
+    reflect: false,
+    xorout: 0x00000000,
+};
+
+const CRC32_POSIX_PARAMS: CRC = CRC {
+    width: 32,
+    poly: 0x04c11db7,
+    init: 0x00000000,
+    reflect: false,
+    xorout: 0xffffffff,
+};
+
+const CRC64_PARAMS: CRC = CRC {
+    width: 64,
+    poly: 0x42f0e1eba9ea3693,
+    init: 0x0000000000000000,
+    reflect: false,
+    xorout: 0x0000000000000000,
+};
+
+const CRC64_XZ_PARAMS: CRC = CRC {
+    width: 64,
+    poly: 0x42f0e1eba9ea3693,
+    init: 0xffffffffffffffff,
+    reflect: true,
+    xorout: 0xffffffffffffffff,
+};
+
+/*
+CRC32 is the CRC-32/ISO-HDLC variant used by zip, gzip and PNG, CRC32C is
+the Castagnoli polynomial used by iSCSI and ext4, CRC64 is CRC-64/ECMA-182
+and CRC64XZ is the reflected variant used by the xz container format.
+CRC32POSIX does not append the message length the way cksum(1) does.
+*/
+pub struct CRC32;
+
+impl CRC32 {
+    pub fn checksum(&self, input: &Message) -> Digest {
+        CRC32_PARAMS.checksum(input)
+    }
+}
+
+pub struct CRC32C;
+
+impl CRC32C {
+    pub fn checksum(&self, input: &Message) -> Digest {
+        CRC32C_PARAMS.checksum(input)
+    }
+}
+
+pub struct CRC32BZIP2;
+
+impl CRC32BZIP2 {
+    pub fn checksum(&self, input: &Message) -> Digest {
+        CRC32_BZIP2_PARAMS.checksum(input)
+    }
+}
+
+pub struct CRC32MPEG2;
+
+impl CRC32MPEG2 {
+    pub fn checksum(&self, input: &Message) -> Digest {
+        CRC32_MPEG2_PARAMS.checksum(input)
+    }
+}
+
+pub struct CRC32POSIX;
+
+impl CRC32POSIX {
+    pub fn checksum(&self, input: &Message) -> Digest {
+        CRC32_POSIX_PARAMS.checksum(input)
+    }
+}
+
+pub struct CRC64;
+
+impl CRC64 {
+    pub fn checksum(&self, input: &Message) -> Digest {
+        CRC64_PARAMS.checksum(input)
+    }
+}
+
+pub struct CRC64XZ;
+
+impl CRC64XZ {
+    pub fn checksum(&self, input: &Message) -> Digest {
+        CRC64_XZ_PARAMS.checksum(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CHECK: &str = "123456789";
+
+    #[test]
+    fn test_crc_presets_on_check_value() {
+        let input = Message::from_string(CHECK);
+
+        assert_eq!(CRC32.checksum(&input).to_hex(), "cbf43926");
+        assert_eq!(CRC32C.checksum(&input).to_hex(), "e3069283");
+        assert_eq!(CRC32BZIP2.checksum(&input).to_hex(), "fc891918");
+        assert_eq!(CRC32MPEG2.checksum(&input).to_hex(), "0376e6e7");
+        assert_eq!(CRC32POSIX.checksum(&input).to_hex(), "765e7680");
+        assert_eq!(CRC64.checksum(&input).to_hex(), "6c40df5f0b497347");
+        assert_eq!(CRC64XZ.checksum(&input).to_hex(), "995dc9bbdf1939fa");
+    }
+
+    #[test]
+    fn test_crc32_on_png_chunk() {
+        let input = Message::from_string("IEND");
+        assert_eq!(CRC32.checksum(&input).to_hex(), "ae426082");
+        assert_eq!(
+            CRC32.checksum(&Message::from_string("")).to_hex(),
+            "00000000"
+        );
+    }
+
+    #[test]
+    fn test_crc_custom_parameters() {
+        let input = Message::from_string(CHECK);
+        let tests = [
+            ((5, 0x05, 0x1f, true, 0x1f), "19"),
+            ((8, 0x07, 0x00, false, 0x00), "f4"),
+            ((16, 0x8005, 0x0000, true, 0x0000), "bb3d"),
+            ((16, 0x1021, 0xffff, false, 0x0000), "29b1"),
+            ((24, 0x864cfb, 0xb704ce, false, 0x000000), "21cf02"),
+        ];
+
+        for ((width, poly, init, reflect, xorout), expected) in tests {
+            let crc = CRC::new(width, poly, init, reflect, xorout).unwrap();
+            assert_eq!(crc.checksum(&input).to_hex(), expected);
+        }
+    }
+
+    #[test]
+    fn test_crc_invalid_width() {
+        assert!(CRC::new(0, 0x07, 0, false, 0).is_err());
+        assert!(CRC::new(65, 0x07, 0, false, 0).is_err());
+    }
+}
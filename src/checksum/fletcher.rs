@@ -0,0 +1,72 @@
+use crate::hash::{Digest, Endianness, Message};
+
+pub struct Fletcher16;
+
+impl Fletcher16 {
+    pub fn checksum(&self, input: &Message) -> Digest {
+        let mut sum1: u16 = 0;
+        let mut sum2: u16 = 0;
+        for &byte in input.to_slice() {
+            sum1 = (sum1 + byte as u16) % 255;
+            sum2 = (sum2 + sum1) % 255;
+        }
+        Digest::from_u8(&((sum2 << 8) | sum1).to_be_bytes())
+    }
+}
+
+/*
+Fletcher-32 sums 16-bit words. The words are read in little-endian order
+and an odd trailing byte is padded with a zero byte, which matches the
+common reference implementation.
+*/
+pub struct Fletcher32;
+
+impl Fletcher32 {
+    pub fn checksum(&self, input: &Message) -> Digest {
+        let mut sum1: u32 = 0;
+        let mut sum2: u32 = 0;
+        for chunk in input.to_slice().chunks(2) {
+            let word: u32 = match chunk {
+                [low, high] => u16::from_le_bytes([*low, *high]) as u32,
+                _ => chunk[0] as u32,
+            };
+            sum1 = (sum1 + word) % 65535;
+            sum2 = (sum2 + sum1) % 65535;
+        }
+        Digest::from_u32(&[(sum2 << 16) | sum1], Endianness::Big)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fletcher16() {
+        let tests = [("", "0000"), ("abcde", "c8f0"), ("abcdef", "2057")];
+
+        for (message, expected) in tests {
+            assert_eq!(
+                Fletcher16.checksum(&Message::from_string(message)).to_hex(),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_fletcher32() {
+        let tests = [
+            ("", "00000000"),
+            ("abcde", "f04fc729"),
+            ("abcdef", "56502d2a"),
+            ("abcdefgh", "ebe19591"),
+        ];
+
+        for (message, expected) in tests {
+            assert_eq!(
+                Fletcher32.checksum(&Message::from_string(message)).to_hex(),
+                expected
+            );
+        }
+    }
+}
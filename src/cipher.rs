@@ -1 +1,53 @@
+use std::{error, fmt};
 
+pub mod aes;
+pub mod blowfish;
+pub mod cbc;
+pub mod cfb;
+pub mod ctr;
+pub mod ecb;
+pub mod ofb;
+pub mod padding;
+pub mod threefish;
+
+#[derive(Debug)]
+pub enum CipherError {
+    InvalidBlockLengthError,
+    InvalidCounterLengthError,
+    InvalidIvLengthError,
+    InvalidKeyLengthError,
+    InvalidPaddingError,
+    InvalidSegmentSizeError,
+    InvalidTweakLengthError,
+    KeystreamExhaustedError,
+}
+
+impl fmt::Display for CipherError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CipherError::InvalidBlockLengthError => write!(f, "Invalid block length provided"),
+            CipherError::InvalidCounterLengthError => {
+                write!(f, "Invalid counter length provided")
+            }
+            CipherError::InvalidIvLengthError => write!(f, "Invalid IV length provided"),
+            CipherError::InvalidKeyLengthError => write!(f, "Invalid key length provided"),
+            CipherError::InvalidPaddingError => write!(f, "Invalid padding found"),
+            CipherError::InvalidSegmentSizeError => write!(f, "Invalid segment size provided"),
+            CipherError::InvalidTweakLengthError => write!(f, "Invalid tweak length provided"),
+            CipherError::KeystreamExhaustedError => write!(f, "Keystream exhausted"),
+        }
+    }
+}
+
+impl error::Error for CipherError {}
+
+/*
+Common interface over the block ciphers of this module, used by the
+modes of operation and by constructions such as CMAC that work with any
+of them.
+*/
+pub trait BlockCipher {
+    fn block_size(&self) -> usize;
+    fn encrypt_block(&self, block: &[u8]) -> Result<Vec<u8>, CipherError>;
+    fn decrypt_block(&self, block: &[u8]) -> Result<Vec<u8>, CipherError>;
+}
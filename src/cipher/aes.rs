@@ -0,0 +1,291 @@
+use crate::cipher::{BlockCipher, CipherError};
+use crate::secret;
+
+const BLOCK_SIZE: usize = 16;
+
+pub(crate) const S_BOX: [u8; 256] = [
+    0x63, 0x7c, 0x77, 0x7b, 0xf2, 0x6b, 0x6f, 0xc5, 0x30, 0x01, 0x67, 0x2b, 0xfe, 0xd7, 0xab, 0x76,
+    0xca, 0x82, 0xc9, 0x7d, 0xfa, 0x59, 0x47, 0xf0, 0xad, 0xd4, 0xa2, 0xaf, 0x9c, 0xa4, 0x72, 0xc0,
+    0xb7, 0xfd, 0x93, 0x26, 0x36, 0x3f, 0xf7, 0xcc, 0x34, 0xa5, 0xe5, 0xf1, 0x71, 0xd8, 0x31, 0x15,
+    0x04, 0xc7, 0x23, 0xc3, 0x18, 0x96, 0x05, 0x9a, 0x07, 0x12, 0x80, 0xe2, 0xeb, 0x27, 0xb2, 0x75,
+    0x09, 0x83, 0x2c, 0x1a, 0x1b, 0x6e, 0x5a, 0xa0, 0x52, 0x3b, 0xd6, 0xb3, 0x29, 0xe3, 0x2f, 0x84,
+    0x53, 0xd1, 0x00, 0xed, 0x20, 0xfc, 0xb1, 0x5b, 0x6a, 0xcb, 0xbe, 0x39, 0x4a, 0x4c, 0x58, 0xcf,
+    0xd0, 0xef, 0xaa, 0xfb, 0x43, 0x4d, 0x33, 0x85, 0x45, 0xf9, 0x02, 0x7f, 0x50, 0x3c, 0x9f, 0xa8,
+    0x51, 0xa3, 0x40, 0x8f, 0x92, 0x9d, 0x38, 0xf5, 0xbc, 0xb6, 0xda, 0x21, 0x10, 0xff, 0xf3, 0xd2,
+    0xcd, 0x0c, 0x13, 0xec, 0x5f, 0x97, 0x44, 0x17, 0xc4, 0xa7, 0x7e, 0x3d, 0x64, 0x5d, 0x19, 0x73,
+    0x60, 0x81, 0x4f, 0xdc, 0x22, 0x2a, 0x90, 0x88, 0x46, 0xee, 0xb8, 0x14, 0xde, 0x5e, 0x0b, 0xdb,
+    0xe0, 0x32, 0x3a, 0x0a, 0x49, 0x06, 0x24, 0x5c, 0xc2, 0xd3, 0xac, 0x62, 0x91, 0x95, 0xe4, 0x79,
+    0xe7, 0xc8, 0x37, 0x6d, 0x8d, 0xd5, 0x4e, 0xa9, 0x6c, 0x56, 0xf4, 0xea, 0x65, 0x7a, 0xae, 0x08,
+    0xba, 0x78, 0x25, 0x2e, 0x1c, 0xa6, 0xb4, 0xc6, 0xe8, 0xdd, 0x74, 0x1f, 0x4b, 0xbd, 0x8b, 0x8a,
+    0x70, 0x3e, 0xb5, 0x66, 0x48, 0x03, 0xf6, 0x0e, 0x61, 0x35, 0x57, 0xb9, 0x86, 0xc1, 0x1d, 0x9e,
+    0xe1, 0xf8, 0x98, 0x11, 0x69, 0xd9, 0x8e, 0x94, 0x9b, 0x1e, 0x87, 0xe9, 0xce, 0x55, 0x28, 0xdf,
+    0x8c, 0xa1, 0x89, 0x0d, 0xbf, 0xe6, 0x42, 0x68, 0x41, 0x99, 0x2d, 0x0f, 0xb0, 0x54, 0xbb, 0x16,
+];
+
+const INV_S_BOX: [u8; 256] = [
+    0x52, 0x09, 0x6a, 0xd5, 0x30, 0x36, 0xa5, 0x38, 0xbf, 0x40, 0xa3, 0x9e, 0x81, 0xf3, 0xd7, 0xfb,
+    0x7c, 0xe3, 0x39, 0x82, 0x9b, 0x2f, 0xff, 0x87, 0x34, 0x8e, 0x43, 0x44, 0xc4, 0xde, 0xe9, 0xcb,
+    0x54, 0x7b, 0x94, 0x32, 0xa6, 0xc2, 0x23, 0x3d, 0xee, 0x4c, 0x95, 0x0b, 0x42, 0xfa, 0xc3, 0x4e,
+    0x08, 0x2e, 0xa1, 0x66, 0x28, 0xd9, 0x24, 0xb2, 0x76, 0x5b, 0xa2, 0x49, 0x6d, 0x8b, 0xd1, 0x25,
+    0x72, 0xf8, 0xf6, 0x64, 0x86, 0x68, 0x98, 0x16, 0xd4, 0xa4, 0x5c, 0xcc, 0x5d, 0x65, 0xb6, 0x92,
+    0x6c, 0x70, 0x48, 0x50, 0xfd, 0xed, 0xb9, 0xda, 0x5e, 0x15, 0x46, 0x57, 0xa7, 0x8d, 0x9d, 0x84,
+    0x90, 0xd8, 0xab, 0x00, 0x8c, 0xbc, 0xd3, 0x0a, 0xf7, 0xe4, 0x58, 0x05, 0xb8, 0xb3, 0x45, 0x06,
+    0xd0, 0x2c, 0x1e, 0x8f, 0xca, 0x3f, 0x0f, 0x02, 0xc1, 0xaf, 0xbd, 0x03, 0x01, 0x13, 0x8a, 0x6b,
+    0x3a, 0x91, 0x11, 0x41, 0x4f, 0x67, 0xdc, 0xea, 0x97, 0xf2, 0xcf, 0xce, 0xf0, 0xb4, 0xe6, 0x73,
+    0x96, 0xac, 0x74, 0x22, 0xe7, 0xad, 0x35, 0x85, 0xe2, 0xf9, 0x37, 0xe8, 0x1c, 0x75, 0xdf, 0x6e,
+    0x47, 0xf1, 0x1a, 0x71, 0x1d, 0x29, 0xc5, 0x89, 0x6f, 0xb7, 0x62, 0x0e, 0xaa, 0x18, 0xbe, 0x1b,
+    0xfc, 0x56, 0x3e, 0x4b, 0xc6, 0xd2, 0x79, 0x20, 0x9a, 0xdb, 0xc0, 0xfe, 0x78, 0xcd, 0x5a, 0xf4,
+    0x1f, 0xdd, 0xa8, 0x33, 0x88, 0x07, 0xc7, 0x31, 0xb1, 0x12, 0x10, 0x59, 0x27, 0x80, 0xec, 0x5f,
+    0x60, 0x51, 0x7f, 0xa9, 0x19, 0xb5, 0x4a, 0x0d, 0x2d, 0xe5, 0x7a, 0x9f, 0x93, 0xc9, 0x9c, 0xef,
+    0xa0, 0xe0, 0x3b, 0x4d, 0xae, 0x2a, 0xf5, 0xb0, 0xc8, 0xeb, 0xbb, 0x3c, 0x83, 0x53, 0x99, 0x61,
+    0x17, 0x2b, 0x04, 0x7e, 0xba, 0x77, 0xd6, 0x26, 0xe1, 0x69, 0x14, 0x63, 0x55, 0x21, 0x0c, 0x7d,
+];
+
+const RCON: [u8; 10] = [0x01, 0x02, 0x04, 0x08, 0x10, 0x20, 0x40, 0x80, 0x1b, 0x36];
+
+fn xtime(x: u8) -> u8 {
+    (x << 1) ^ if x & 0x80 != 0 { 0x1b } else { 0x00 }
+}
+
+fn sub_bytes(state: &mut [u8; 16]) {
+    state.iter_mut().for_each(|x| *x = S_BOX[*x as usize]);
+}
+
+/*
+The state is stored column by column, so the byte in row r and column c
+lives at index 4 * c + r and row r is rotated left by r positions.
+*/
+fn shift_rows(state: &mut [u8; 16]) {
+    let copy: [u8; 16] = *state;
+    for (i, x) in state.iter_mut().enumerate() {
+        *x = copy[(i + 4 * (i % 4)) % 16];
+    }
+}
+
+fn inv_sub_bytes(state: &mut [u8; 16]) {
+    state.iter_mut().for_each(|x| *x = INV_S_BOX[*x as usize]);
+}
+
+fn inv_shift_rows(state: &mut [u8; 16]) {
+    let copy: [u8; 16] = *state;
+    for (i, x) in state.iter_mut().enumerate() {
+        *x = copy[(i + 12 * (i % 4)) % 16];
+    }
+}
+
+fn mix_columns(state: &mut [u8; 16]) {
+    for column in state.chunks_exact_mut(4) {
+        let [a0, a1, a2, a3] = [column[0], column[1], column[2], column[3]];
+        let all: u8 = a0 ^ a1 ^ a2 ^ a3;
+        column[0] ^= all ^ xtime(a0 ^ a1);
+        column[1] ^= all ^ xtime(a1 ^ a2);
+        column[2] ^= all ^ xtime(a2 ^ a3);
+        column[3] ^= all ^ xtime(a3 ^ a0);
+    }
+}
+
+/*
+InvMixColumns is MixColumns preceded by multiplying every column by
+{04}x^2 + {05}, which folds the inverse matrix into the forward one.
+*/
+fn inv_mix_columns(state: &mut [u8; 16]) {
+    for column in state.chunks_exact_mut(4) {
+        let u: u8 = xtime(xtime(column[0] ^ column[2]));
+        let v: u8 = xtime(xtime(column[1] ^ column[3]));
+        column[0] ^= u;
+        column[1] ^= v;
+        column[2] ^= u;
+        column[3] ^= v;
+    }
+    mix_columns(state);
+}
+
+fn add_round_key(state: &mut [u8; 16], round_key: &[u8; 16]) {
+    for (s, k) in state.iter_mut().zip(round_key) {
+        *s ^= k;
+    }
+}
+
+/*
+The AES block cipher from FIPS 197. The key length selects the variant:
+16, 24 or 32 bytes for AES-128, AES-192 and AES-256, with 10, 12 and 14
+rounds respectively.
+*/
+pub struct AES {
+    round_keys: Vec<[u8; 16]>,
+}
+
+impl AES {
+    pub fn new(key: &[u8]) -> Result<Self, CipherError> {
+        if ![16, 24, 32].contains(&key.len()) {
+            return Err(CipherError::InvalidKeyLengthError);
+        }
+        Ok(Self {
+            round_keys: Self::expand_key(key),
+        })
+    }
+
+    fn expand_key(key: &[u8]) -> Vec<[u8; 16]> {
+        let mut words: Vec<[u8; 4]> = key
+            .chunks_exact(4)
+            .map(|chunk| chunk.try_into().unwrap())
+            .collect();
+
+        let nk: usize = words.len();
+
+        for i in nk..4 * (nk + 7) {
+            let mut temp: [u8; 4] = words[i - 1];
+            if i % nk == 0 {
+                temp.rotate_left(1);
+                temp.iter_mut().for_each(|x| *x = S_BOX[*x as usize]);
+                temp[0] ^= RCON[i / nk - 1];
+            } else if nk > 6 && i % nk == 4 {
+                temp.iter_mut().for_each(|x| *x = S_BOX[*x as usize]);
+            }
+            let previous: [u8; 4] = words[i - nk];
+            words.push(std::array::from_fn(|j| previous[j] ^ temp[j]));
+        }
+
+        words
+            .chunks_exact(4)
+            .map(|chunk| std::array::from_fn(|i| chunk[i / 4][i % 4]))
+            .collect()
+    }
+
+    pub fn block_size(&self) -> usize {
+        BLOCK_SIZE
+    }
+
+    pub fn encrypt_block(&self, block: &[u8]) -> Result<Vec<u8>, CipherError> {
+        let mut state: [u8; 16] = block
+            .try_into()
+            .map_err(|_| CipherError::InvalidBlockLengthError)?;
+        let rounds: usize = self.round_keys.len() - 1;
+
+        add_round_key(&mut state, &self.round_keys[0]);
+        for round in 1..=rounds {
+            sub_bytes(&mut state);
+            shift_rows(&mut state);
+            if round != rounds {
+                mix_columns(&mut state);
+            }
+            add_round_key(&mut state, &self.round_keys[round]);
+        }
+        Ok(state.to_vec())
+    }
+
+    pub fn decrypt_block(&self, block: &[u8]) -> Result<Vec<u8>, CipherError> {
+        let mut state: [u8; 16] = block
+            .try_into()
+            .map_err(|_| CipherError::InvalidBlockLengthError)?;
+        let rounds: usize = self.round_keys.len() - 1;
+
+        add_round_key(&mut state, &self.round_keys[rounds]);
+        for round in (0..rounds).rev() {
+            inv_shift_rows(&mut state);
+            inv_sub_bytes(&mut state);
+            add_round_key(&mut state, &self.round_keys[round]);
+            if round != 0 {
+                inv_mix_columns(&mut state);
+            }
+        }
+        Ok(state.to_vec())
+    }
+}
+
+impl BlockCipher for AES {
+    fn block_size(&self) -> usize {
+        AES::block_size(self)
+    }
+
+    fn encrypt_block(&self, block: &[u8]) -> Result<Vec<u8>, CipherError> {
+        AES::encrypt_block(self, block)
+    }
+
+    fn decrypt_block(&self, block: &[u8]) -> Result<Vec<u8>, CipherError> {
+        AES::decrypt_block(self, block)
+    }
+}
+
+impl Drop for AES {
+    fn drop(&mut self) {
+        secret::zeroize(&mut self.round_keys);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hash::Message;
+
+    #[test]
+    fn test_aes_fips197() {
+        let tests = [
+            (
+                "000102030405060708090a0b0c0d0e0f",
+                "00112233445566778899aabbccddeeff",
+                "69c4e0d86a7b0430d8cdb78070b4c55a",
+            ),
+            (
+                "2b7e151628aed2a6abf7158809cf4f3c",
+                "3243f6a8885a308d313198a2e0370734",
+                "3925841d02dc09fbdc118597196a0b32",
+            ),
+            (
+                "000102030405060708090a0b0c0d0e0f1011121314151617",
+                "00112233445566778899aabbccddeeff",
+                "dda97ca4864cdfe06eaf70a0ec0d7191",
+            ),
+            (
+                "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f",
+                "00112233445566778899aabbccddeeff",
+                "8ea2b7ca516745bfeafc49904b496089",
+            ),
+        ];
+
+        for (key, plaintext, expected) in tests {
+            let aes = AES::new(Message::from_hex(key).unwrap().to_slice()).unwrap();
+            let plaintext = Message::from_hex(plaintext).unwrap();
+            let ciphertext = aes.encrypt_block(plaintext.to_slice()).unwrap();
+            assert_eq!(ciphertext, Message::from_hex(expected).unwrap().to_slice());
+            assert_eq!(
+                aes.decrypt_block(&ciphertext).unwrap(),
+                plaintext.to_slice()
+            );
+        }
+    }
+
+    #[test]
+    fn test_aes_key_expansion() {
+        let tests = [
+            (
+                "2b7e151628aed2a6abf7158809cf4f3c",
+                "d014f9a8c9ee2589e13f0cc8b6630ca6",
+            ),
+            (
+                "8e73b0f7da0e6452c810f32b809079e562f8ead2522c6b7b",
+                "e98ba06f448c773c8ecc720401002202",
+            ),
+            (
+                "603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4",
+                "fe4890d1e6188d0b046df344706c631e",
+            ),
+        ];
+
+        for (key, last_round_key) in tests {
+            let aes = AES::new(Message::from_hex(key).unwrap().to_slice()).unwrap();
+            assert_eq!(
+                aes.round_keys.last().unwrap(),
+                Message::from_hex(last_round_key).unwrap().to_slice()
+            );
+        }
+    }
+
+    #[test]
+    fn test_aes_invalid_lengths() {
+        assert!(AES::new(&[0; 15]).is_err());
+        assert!(AES::new(&[0; 20]).is_err());
+        assert!(AES::new(&[0; 16]).unwrap().decrypt_block(&[0; 17]).is_err());
+        assert!(AES::new(&[0; 16]).unwrap().encrypt_block(&[0; 15]).is_err());
+    }
+}
@@ -0,0 +1,139 @@
+use crate::cipher::padding::Padding;
+use crate::cipher::{BlockCipher, CipherError};
+
+/*
+Cipher block chaining mode from NIST SP 800-38A. Every plaintext block is
+XORed with the previous ciphertext block, or with the IV for the first
+one, before being encrypted. The IV must be unpredictable and is passed
+with every message. The padding is checked in constant time, but decrypt
+still fails when the padding is wrong, so a party that can submit chosen
+ciphertexts and observe that failure can recover the plaintext. CBC must
+therefore only decrypt ciphertexts that were first authenticated with a
+MAC, and must never be exposed to attacker-chosen ciphertexts otherwise.
+*/
+pub struct CBC<C: BlockCipher> {
+    cipher: C,
+    padding: Padding,
+}
+
+impl<C: BlockCipher> CBC<C> {
+    pub fn new(cipher: C, padding: Padding) -> Self {
+        Self { cipher, padding }
+    }
+
+    pub fn encrypt(&self, iv: &[u8], plaintext: &[u8]) -> Result<Vec<u8>, CipherError> {
+        let block_size: usize = self.cipher.block_size();
+        if iv.len() != block_size {
+            return Err(CipherError::InvalidIvLengthError);
+        }
+        let padded: Vec<u8> = self.padding.pad(plaintext, block_size);
+        if !padded.len().is_multiple_of(block_size) {
+            return Err(CipherError::InvalidBlockLengthError);
+        }
+
+        let mut ciphertext: Vec<u8> = Vec::with_capacity(padded.len());
+        let mut previous: Vec<u8> = iv.to_vec();
+        for block in padded.chunks_exact(block_size) {
+            let input: Vec<u8> = block.iter().zip(&previous).map(|(a, b)| a ^ b).collect();
+            previous = self.cipher.encrypt_block(&input)?;
+            ciphertext.extend(&previous);
+        }
+        Ok(ciphertext)
+    }
+
+    pub fn decrypt(&self, iv: &[u8], ciphertext: &[u8]) -> Result<Vec<u8>, CipherError> {
+        let block_size: usize = self.cipher.block_size();
+        if iv.len() != block_size {
+            return Err(CipherError::InvalidIvLengthError);
+        }
+        if !ciphertext.len().is_multiple_of(block_size) {
+            return Err(CipherError::InvalidBlockLengthError);
+        }
+
+        let mut plaintext: Vec<u8> = Vec::with_capacity(ciphertext.len());
+        let mut previous: &[u8] = iv;
+        for block in ciphertext.chunks_exact(block_size) {
+            let output: Vec<u8> = self.cipher.decrypt_block(block)?;
+            plaintext.extend(output.iter().zip(previous).map(|(a, b)| a ^ b));
+            previous = block;
+        }
+        self.padding.unpad(&plaintext, block_size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cipher::aes::AES;
+    use crate::hash::Message;
+
+    const PLAINTEXT: &str = "6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e5130c81c46a35ce411e5fbc1191a0a52eff69f2445df4f9b17ad2b417be66c3710";
+    const IV: &str = "000102030405060708090a0b0c0d0e0f";
+
+    #[test]
+    fn test_cbc_aes_sp800_38a() {
+        let tests = [
+            (
+                "2b7e151628aed2a6abf7158809cf4f3c",
+                "7649abac8119b246cee98e9b12e9197d5086cb9b507219ee95db113a917678b273bed6b8e3c1743b7116e69e222295163ff1caa1681fac09120eca307586e1a7",
+            ),
+            (
+                "603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4",
+                "f58c4c04d6e5f1ba779eabfb5f7bfbd69cfc4e967edb808d679f777bc6702c7d39f23369a9d9bacfa530e26304231461b2eb05e2c39be9fcda6c19078c6a9d1b",
+            ),
+        ];
+
+        for (key, expected) in tests {
+            let aes = AES::new(Message::from_hex(key).unwrap().to_slice()).unwrap();
+            let cbc: CBC<AES> = CBC::new(aes, Padding::NoPadding);
+            let iv = Message::from_hex(IV).unwrap();
+            let plaintext = Message::from_hex(PLAINTEXT).unwrap();
+            let ciphertext = cbc.encrypt(iv.to_slice(), plaintext.to_slice()).unwrap();
+            assert_eq!(ciphertext, Message::from_hex(expected).unwrap().to_slice());
+            assert_eq!(
+                cbc.decrypt(iv.to_slice(), &ciphertext).unwrap(),
+                plaintext.to_slice()
+            );
+        }
+    }
+
+    #[test]
+    fn test_cbc_padding() {
+        let key = Message::from_hex("2b7e151628aed2a6abf7158809cf4f3c").unwrap();
+        let iv = Message::from_hex(IV).unwrap();
+        let tests = [
+            (
+                Padding::Pkcs7,
+                "7649abac8119b246cee98e9b12e9197d8964e0b149c10b7b682e6e39aaeb731c",
+            ),
+            (
+                Padding::Iso7816,
+                "7649abac8119b246cee98e9b12e9197d7bf58f5976824ae38b3866effb261160",
+            ),
+            (
+                Padding::AnsiX923,
+                "7649abac8119b246cee98e9b12e9197d9232ac254b8f94dc2df7c71d0d68c664",
+            ),
+        ];
+
+        for (padding, expected) in tests {
+            let cbc: CBC<AES> = CBC::new(AES::new(key.to_slice()).unwrap(), padding);
+            let message = Message::from_hex(PLAINTEXT).unwrap();
+            let plaintext = &message.to_slice()[..16];
+            let ciphertext = cbc.encrypt(iv.to_slice(), plaintext).unwrap();
+            assert_eq!(ciphertext, Message::from_hex(expected).unwrap().to_slice());
+            assert_eq!(cbc.decrypt(iv.to_slice(), &ciphertext).unwrap(), plaintext);
+        }
+    }
+
+    #[test]
+    fn test_cbc_invalid_input() {
+        let cbc: CBC<AES> = CBC::new(AES::new(&[0; 16]).unwrap(), Padding::Pkcs7);
+        assert!(cbc.encrypt(&[0; 15], b"data").is_err());
+        assert!(cbc.decrypt(&[0; 16], &[0; 15]).is_err());
+        let ciphertext = CBC::new(AES::new(&[0; 16]).unwrap(), Padding::NoPadding)
+            .encrypt(&[0; 16], &[0; 16])
+            .unwrap();
+        assert!(cbc.decrypt(&[0; 16], &ciphertext).is_err());
+    }
+}
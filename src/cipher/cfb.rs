@@ -0,0 +1,138 @@
+use crate::cipher::{BlockCipher, CipherError};
+
+/*
+Cipher feedback mode from NIST SP 800-38A with a segment size of 1 bit,
+any whole number of bytes, or the full block. The shift register starts
+at the IV and takes in every ciphertext segment, so no padding is needed
+and a final partial segment is simply truncated.
+*/
+pub struct CFB<C: BlockCipher> {
+    cipher: C,
+    segment_size: usize,
+}
+
+impl<C: BlockCipher> CFB<C> {
+    pub fn new(cipher: C, segment_size: usize) -> Result<Self, CipherError> {
+        let bytes: usize = segment_size / 8;
+        if segment_size != 1
+            && (!segment_size.is_multiple_of(8) || bytes == 0 || bytes > cipher.block_size())
+        {
+            return Err(CipherError::InvalidSegmentSizeError);
+        }
+        Ok(Self {
+            cipher,
+            segment_size,
+        })
+    }
+
+    pub fn encrypt(&self, iv: &[u8], plaintext: &[u8]) -> Result<Vec<u8>, CipherError> {
+        self.process(iv, plaintext, false)
+    }
+
+    pub fn decrypt(&self, iv: &[u8], ciphertext: &[u8]) -> Result<Vec<u8>, CipherError> {
+        self.process(iv, ciphertext, true)
+    }
+
+    fn process(&self, iv: &[u8], input: &[u8], decrypt: bool) -> Result<Vec<u8>, CipherError> {
+        if iv.len() != self.cipher.block_size() {
+            return Err(CipherError::InvalidIvLengthError);
+        }
+        let mut register: Vec<u8> = iv.to_vec();
+        let mut output: Vec<u8> = Vec::with_capacity(input.len());
+
+        if self.segment_size == 1 {
+            for &byte in input {
+                let mut out: u8 = 0;
+                for bit in (0..8).rev() {
+                    let keystream: u8 = self.cipher.encrypt_block(&register)?[0] >> 7;
+                    let x: u8 = (byte >> bit) & 1;
+                    let y: u8 = x ^ keystream;
+                    out |= y << bit;
+                    shift_in_bit(&mut register, if decrypt { x } else { y });
+                }
+                output.push(out);
+            }
+            return Ok(output);
+        }
+
+        for segment in input.chunks(self.segment_size / 8) {
+            let keystream: Vec<u8> = self.cipher.encrypt_block(&register)?;
+            let out: Vec<u8> = segment.iter().zip(&keystream).map(|(a, b)| a ^ b).collect();
+            register.drain(..segment.len());
+            register.extend_from_slice(if decrypt { segment } else { &out });
+            output.extend(out);
+        }
+        Ok(output)
+    }
+}
+
+fn shift_in_bit(register: &mut [u8], bit: u8) {
+    for i in 0..register.len() {
+        let carry: u8 = register.get(i + 1).map_or(bit, |next| next >> 7);
+        register[i] = (register[i] << 1) | carry;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cipher::aes::AES;
+    use crate::hash::Message;
+
+    const PLAINTEXT: &str = "6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e5130c81c46a35ce411e5fbc1191a0a52eff69f2445df4f9b17ad2b417be66c3710";
+    const IV: &str = "000102030405060708090a0b0c0d0e0f";
+
+    #[test]
+    fn test_cfb_aes_sp800_38a() {
+        let key128 = "2b7e151628aed2a6abf7158809cf4f3c";
+        let key256 = "603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4";
+        let tests = [
+            (key128, 1, 2, "68b3"),
+            (key128, 8, 18, "3b79424c9c0dd436bace9e0ed4586a4f32b9"),
+            (
+                key128,
+                128,
+                64,
+                "3b3fd92eb72dad20333449f8e83cfb4ac8a64537a0b3a93fcde3cdad9f1ce58b26751f67a3cbb140b1808cf187a4f4dfc04b05357c5d1c0eeac4c66f9ff7f2e6",
+            ),
+            (key256, 8, 18, "dc1f1a8520a64db55fcc8ac554844e889700"),
+            (
+                key256,
+                128,
+                64,
+                "dc7e84bfda79164b7ecd8486985d386039ffed143b28b1c832113c6331e5407bdf10132415e54b92a13ed0a8267ae2f975a385741ab9cef82031623d55b1e471",
+            ),
+        ];
+
+        for (key, segment_size, length, expected) in tests {
+            let aes = AES::new(Message::from_hex(key).unwrap().to_slice()).unwrap();
+            let cfb: CFB<AES> = CFB::new(aes, segment_size).unwrap();
+            let iv = Message::from_hex(IV).unwrap();
+            let message = Message::from_hex(PLAINTEXT).unwrap();
+            let plaintext = &message.to_slice()[..length];
+            let ciphertext = cfb.encrypt(iv.to_slice(), plaintext).unwrap();
+            assert_eq!(ciphertext, Message::from_hex(expected).unwrap().to_slice());
+            assert_eq!(cfb.decrypt(iv.to_slice(), &ciphertext).unwrap(), plaintext);
+        }
+    }
+
+    #[test]
+    fn test_cfb_partial_segment() {
+        let cfb: CFB<AES> = CFB::new(AES::new(&[0; 16]).unwrap(), 128).unwrap();
+        let ciphertext = cfb.encrypt(&[0; 16], b"twenty-one bytes long").unwrap();
+        assert_eq!(ciphertext.len(), 21);
+        assert_eq!(
+            cfb.decrypt(&[0; 16], &ciphertext).unwrap(),
+            b"twenty-one bytes long"
+        );
+    }
+
+    #[test]
+    fn test_cfb_invalid_parameters() {
+        assert!(CFB::new(AES::new(&[0; 16]).unwrap(), 0).is_err());
+        assert!(CFB::new(AES::new(&[0; 16]).unwrap(), 4).is_err());
+        assert!(CFB::new(AES::new(&[0; 16]).unwrap(), 136).is_err());
+        let cfb: CFB<AES> = CFB::new(AES::new(&[0; 16]).unwrap(), 8).unwrap();
+        assert!(cfb.encrypt(&[0; 8], b"data").is_err());
+    }
+}
@@ -0,0 +1,181 @@
+use crate::cipher::{BlockCipher, CipherError};
+
+/*
+Counter mode from NIST SP 800-38A. The keystream is the encryption of
+successive counter blocks, starting at the given initial block. By
+default the whole block is incremented as a big-endian integer; with
+with_counter_length only its last bits are, leaving the rest as a nonce.
+As with OFB, the position in the keystream is kept between calls so a
+blob can be processed as a stream of chunks of any size. Once the
+counter has taken every value it can, the keystream would repeat, so
+apply_keystream fails instead of wrapping around.
+*/
+pub struct CTR<C: BlockCipher> {
+    cipher: C,
+    counter: Vec<u8>,
+    counter_length: usize,
+    remaining_blocks: Option<u128>,
+    keystream: Vec<u8>,
+    position: usize,
+}
+
+impl<C: BlockCipher> CTR<C> {
+    pub fn new(cipher: C, initial_counter: &[u8]) -> Result<Self, CipherError> {
+        if initial_counter.len() != cipher.block_size() {
+            return Err(CipherError::InvalidIvLengthError);
+        }
+        let counter_length: usize = initial_counter.len() * 8;
+        Ok(Self {
+            counter: initial_counter.to_vec(),
+            counter_length,
+            remaining_blocks: Self::counter_period(counter_length),
+            keystream: vec![],
+            position: 0,
+            cipher,
+        })
+    }
+
+    /* Number of bits at the end of the block that are incremented, a multiple of 8. */
+    pub fn with_counter_length(mut self, bits: usize) -> Result<Self, CipherError> {
+        if bits == 0 || !bits.is_multiple_of(8) || bits > self.counter.len() * 8 {
+            return Err(CipherError::InvalidCounterLengthError);
+        }
+        self.counter_length = bits;
+        self.remaining_blocks = Self::counter_period(bits);
+        Ok(self)
+    }
+
+    /* None when the counter is too wide for its period to ever be reached. */
+    fn counter_period(bits: usize) -> Option<u128> {
+        1u128.checked_shl(bits as u32)
+    }
+
+    fn increment_counter(&mut self) {
+        let start: usize = self.counter.len() - self.counter_length / 8;
+        for byte in self.counter[start..].iter_mut().rev() {
+            *byte = byte.wrapping_add(1);
+            if *byte != 0 {
+                break;
+            }
+        }
+    }
+
+    pub fn apply_keystream(&mut self, data: &[u8]) -> Result<Vec<u8>, CipherError> {
+        let block_size: usize = self.counter.len();
+        let buffered: usize = self.keystream.len() - self.position;
+        let needed_blocks: usize = data.len().saturating_sub(buffered).div_ceil(block_size);
+        if self
+            .remaining_blocks
+            .is_some_and(|remaining| (needed_blocks as u128) > remaining)
+        {
+            return Err(CipherError::KeystreamExhaustedError);
+        }
+
+        let mut output: Vec<u8> = Vec::with_capacity(data.len());
+        for byte in data {
+            if self.position == self.keystream.len() {
+                self.keystream = self.cipher.encrypt_block(&self.counter)?;
+                self.increment_counter();
+                if let Some(remaining) = self.remaining_blocks.as_mut() {
+                    *remaining -= 1;
+                }
+                self.position = 0;
+            }
+            output.push(byte ^ self.keystream[self.position]);
+            self.position += 1;
+        }
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cipher::aes::AES;
+    use crate::hash::Message;
+
+    const PLAINTEXT: &str = "6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e5130c81c46a35ce411e5fbc1191a0a52eff69f2445df4f9b17ad2b417be66c3710";
+    const COUNTER: &str = "f0f1f2f3f4f5f6f7f8f9fafbfcfdfeff";
+
+    #[test]
+    fn test_ctr_aes_sp800_38a() {
+        let tests = [
+            (
+                "2b7e151628aed2a6abf7158809cf4f3c",
+                "874d6191b620e3261bef6864990db6ce9806f66b7970fdff8617187bb9fffdff5ae4df3edbd5d35e5b4f09020db03eab1e031dda2fbe03d1792170a0f3009cee",
+            ),
+            (
+                "603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4",
+                "601ec313775789a5b7a7f504bbf3d228f443e3ca4d62b59aca84e990cacaf5c52b0930daa23de94ce87017ba2d84988ddfc9c58db67aada613c2dd08457941a6",
+            ),
+        ];
+
+        for (key, expected) in tests {
+            let key = Message::from_hex(key).unwrap();
+            let counter = Message::from_hex(COUNTER).unwrap();
+            let plaintext = Message::from_hex(PLAINTEXT).unwrap();
+
+            let mut ctr: CTR<AES> =
+                CTR::new(AES::new(key.to_slice()).unwrap(), counter.to_slice()).unwrap();
+            let ciphertext = ctr.apply_keystream(plaintext.to_slice()).unwrap();
+            assert_eq!(ciphertext, Message::from_hex(expected).unwrap().to_slice());
+
+            let mut ctr: CTR<AES> =
+                CTR::new(AES::new(key.to_slice()).unwrap(), counter.to_slice()).unwrap();
+            let decrypted: Vec<u8> = ciphertext
+                .chunks(5)
+                .flat_map(|chunk| ctr.apply_keystream(chunk).unwrap())
+                .collect();
+            assert_eq!(decrypted, plaintext.to_slice());
+        }
+    }
+
+    #[test]
+    fn test_ctr_counter_wraps_within_counter_bits() {
+        let mut ctr: CTR<AES> = CTR::new(AES::new(&[0; 16]).unwrap(), &[0xff; 16]).unwrap();
+        ctr.apply_keystream(&[0; 17]).unwrap();
+        assert_eq!(
+            ctr.counter,
+            [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]
+        );
+
+        let mut ctr: CTR<AES> = CTR::new(AES::new(&[0; 16]).unwrap(), &[0xff; 16])
+            .unwrap()
+            .with_counter_length(32)
+            .unwrap();
+        ctr.apply_keystream(&[0; 17]).unwrap();
+        assert_eq!(
+            ctr.counter,
+            [0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0, 0, 0, 1]
+        );
+    }
+
+    #[test]
+    fn test_ctr_keystream_exhaustion() {
+        let mut ctr: CTR<AES> = CTR::new(AES::new(&[0; 16]).unwrap(), &[0x42; 16])
+            .unwrap()
+            .with_counter_length(8)
+            .unwrap();
+        let first: Vec<u8> = ctr.apply_keystream(&[0; 16]).unwrap();
+        ctr.apply_keystream(&[0; 255 * 16 - 1]).unwrap();
+        assert!(matches!(
+            ctr.apply_keystream(&[0; 2]),
+            Err(CipherError::KeystreamExhaustedError)
+        ));
+        assert_eq!(ctr.apply_keystream(&[0; 1]).unwrap().len(), 1);
+        assert!(ctr.apply_keystream(&[0; 1]).is_err());
+        assert_eq!(ctr.counter[15], 0x42);
+        assert_ne!(first, vec![0; 16]);
+    }
+
+    #[test]
+    fn test_ctr_invalid_parameters() {
+        assert!(CTR::new(AES::new(&[0; 16]).unwrap(), &[0; 12]).is_err());
+        for bits in [0, 4, 136] {
+            assert!(CTR::new(AES::new(&[0; 16]).unwrap(), &[0; 16])
+                .unwrap()
+                .with_counter_length(bits)
+                .is_err());
+        }
+    }
+}
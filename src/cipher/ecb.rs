@@ -0,0 +1,89 @@
+use crate::cipher::padding::Padding;
+use crate::cipher::{BlockCipher, CipherError};
+
+/*
+Electronic codebook mode from NIST SP 800-38A, which encrypts every block
+independently. Equal plaintext blocks give equal ciphertext blocks, so it
+is only meant for interoperating with existing data.
+*/
+pub struct ECB<C: BlockCipher> {
+    cipher: C,
+    padding: Padding,
+}
+
+impl<C: BlockCipher> ECB<C> {
+    pub fn new(cipher: C, padding: Padding) -> Self {
+        Self { cipher, padding }
+    }
+
+    pub fn encrypt(&self, plaintext: &[u8]) -> Result<Vec<u8>, CipherError> {
+        let block_size: usize = self.cipher.block_size();
+        let padded: Vec<u8> = self.padding.pad(plaintext, block_size);
+        if !padded.len().is_multiple_of(block_size) {
+            return Err(CipherError::InvalidBlockLengthError);
+        }
+        let mut ciphertext: Vec<u8> = Vec::with_capacity(padded.len());
+        for block in padded.chunks_exact(block_size) {
+            ciphertext.extend(self.cipher.encrypt_block(block)?);
+        }
+        Ok(ciphertext)
+    }
+
+    pub fn decrypt(&self, ciphertext: &[u8]) -> Result<Vec<u8>, CipherError> {
+        let block_size: usize = self.cipher.block_size();
+        if !ciphertext.len().is_multiple_of(block_size) {
+            return Err(CipherError::InvalidBlockLengthError);
+        }
+        let mut plaintext: Vec<u8> = Vec::with_capacity(ciphertext.len());
+        for block in ciphertext.chunks_exact(block_size) {
+            plaintext.extend(self.cipher.decrypt_block(block)?);
+        }
+        self.padding.unpad(&plaintext, block_size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cipher::aes::AES;
+    use crate::cipher::blowfish::Blowfish;
+    use crate::hash::Message;
+
+    const PLAINTEXT: &str = "6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e5130c81c46a35ce411e5fbc1191a0a52eff69f2445df4f9b17ad2b417be66c3710";
+
+    #[test]
+    fn test_ecb_aes_sp800_38a() {
+        let tests = [
+            (
+                "2b7e151628aed2a6abf7158809cf4f3c",
+                "3ad77bb40d7a3660a89ecaf32466ef97f5d3d58503b9699de785895a96fdbaaf43b1cd7f598ece23881b00e3ed0306887b0c785e27e8ad3f8223207104725dd4",
+            ),
+            (
+                "603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4",
+                "f3eed1bdb5d2a03c064b5a7e3db181f8591ccb10d410ed26dc5ba74a31362870b6ed21b99ca6f4f9f153e7b1beafed1d23304b7a39f9f3ff067d8d8f9e24ecc7",
+            ),
+        ];
+
+        for (key, expected) in tests {
+            let aes = AES::new(Message::from_hex(key).unwrap().to_slice()).unwrap();
+            let ecb: ECB<AES> = ECB::new(aes, Padding::NoPadding);
+            let plaintext = Message::from_hex(PLAINTEXT).unwrap();
+            let ciphertext = ecb.encrypt(plaintext.to_slice()).unwrap();
+            assert_eq!(ciphertext, Message::from_hex(expected).unwrap().to_slice());
+            assert_eq!(ecb.decrypt(&ciphertext).unwrap(), plaintext.to_slice());
+        }
+    }
+
+    #[test]
+    fn test_ecb_padding_round_trip() {
+        let ecb: ECB<Blowfish> = ECB::new(Blowfish::new(b"secret key").unwrap(), Padding::Pkcs7);
+        let ciphertext = ecb.encrypt(b"The quick brown fox").unwrap();
+        assert_eq!(ciphertext.len(), 24);
+        assert_eq!(ecb.decrypt(&ciphertext).unwrap(), b"The quick brown fox");
+
+        let ecb: ECB<Blowfish> =
+            ECB::new(Blowfish::new(b"secret key").unwrap(), Padding::NoPadding);
+        assert!(ecb.encrypt(b"The quick brown fox").is_err());
+        assert!(ecb.decrypt(&ciphertext[..20]).is_err());
+    }
+}
@@ -0,0 +1,86 @@
+use crate::cipher::{BlockCipher, CipherError};
+
+/*
+Output feedback mode from NIST SP 800-38A. The keystream is the IV
+encrypted over and over and is XORed with the data, so encryption and
+decryption are the same operation. The position in the keystream is kept
+between calls, which lets a long message be processed in pieces.
+*/
+pub struct OFB<C: BlockCipher> {
+    cipher: C,
+    keystream: Vec<u8>,
+    position: usize,
+}
+
+impl<C: BlockCipher> OFB<C> {
+    pub fn new(cipher: C, iv: &[u8]) -> Result<Self, CipherError> {
+        if iv.len() != cipher.block_size() {
+            return Err(CipherError::InvalidIvLengthError);
+        }
+        Ok(Self {
+            position: iv.len(),
+            keystream: iv.to_vec(),
+            cipher,
+        })
+    }
+
+    pub fn apply_keystream(&mut self, data: &[u8]) -> Vec<u8> {
+        let mut output: Vec<u8> = Vec::with_capacity(data.len());
+        for byte in data {
+            if self.position == self.keystream.len() {
+                self.keystream = self.cipher.encrypt_block(&self.keystream).unwrap();
+                self.position = 0;
+            }
+            output.push(byte ^ self.keystream[self.position]);
+            self.position += 1;
+        }
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cipher::aes::AES;
+    use crate::hash::Message;
+
+    const PLAINTEXT: &str = "6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e5130c81c46a35ce411e5fbc1191a0a52eff69f2445df4f9b17ad2b417be66c3710";
+    const IV: &str = "000102030405060708090a0b0c0d0e0f";
+
+    #[test]
+    fn test_ofb_aes_sp800_38a() {
+        let tests = [
+            (
+                "2b7e151628aed2a6abf7158809cf4f3c",
+                "3b3fd92eb72dad20333449f8e83cfb4a7789508d16918f03f53c52dac54ed8259740051e9c5fecf64344f7a82260edcc304c6528f659c77866a510d9c1d6ae5e",
+            ),
+            (
+                "603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4",
+                "dc7e84bfda79164b7ecd8486985d38604febdc6740d20b3ac88f6ad82a4fb08d71ab47a086e86eedf39d1c5bba97c4080126141d67f37be8538f5a8be740e484",
+            ),
+        ];
+
+        for (key, expected) in tests {
+            let key = Message::from_hex(key).unwrap();
+            let iv = Message::from_hex(IV).unwrap();
+            let plaintext = Message::from_hex(PLAINTEXT).unwrap();
+
+            let mut ofb: OFB<AES> =
+                OFB::new(AES::new(key.to_slice()).unwrap(), iv.to_slice()).unwrap();
+            let ciphertext = ofb.apply_keystream(plaintext.to_slice());
+            assert_eq!(ciphertext, Message::from_hex(expected).unwrap().to_slice());
+
+            let mut ofb: OFB<AES> =
+                OFB::new(AES::new(key.to_slice()).unwrap(), iv.to_slice()).unwrap();
+            let mut decrypted: Vec<u8> = ofb.apply_keystream(&ciphertext[..7]);
+            decrypted.extend(ofb.apply_keystream(&ciphertext[7..40]));
+            decrypted.extend(ofb.apply_keystream(&ciphertext[40..]));
+            assert_eq!(decrypted, plaintext.to_slice());
+        }
+    }
+
+    #[test]
+    fn test_ofb_invalid_iv() {
+        assert!(OFB::new(AES::new(&[0; 16]).unwrap(), &[0; 8]).is_err());
+    }
+}
@@ -0,0 +1,184 @@
+use std::hint;
+
+use crate::cipher::CipherError;
+
+/*
+Padding schemes that extend a message to a whole number of blocks for
+ECB and CBC. PKCS#7 appends n bytes of value n, ISO 7816-4 appends a 0x80
+byte followed by zeros and ANSI X.923 appends zeros followed by the
+count. The first three always add at least one byte so they can be
+removed unambiguously, while zero padding only fills the last block and
+cannot tell trailing zeros of the message apart from the padding.
+*/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Padding {
+    NoPadding,
+    Pkcs7,
+    Iso7816,
+    AnsiX923,
+    Zero,
+}
+
+impl Padding {
+    pub fn pad(&self, data: &[u8], block_size: usize) -> Vec<u8> {
+        let n: usize = block_size - data.len() % block_size;
+        let mut padded: Vec<u8> = data.to_vec();
+        match self {
+            Padding::NoPadding => {}
+            Padding::Pkcs7 => padded.resize(data.len() + n, n as u8),
+            Padding::Iso7816 => {
+                padded.push(0x80);
+                padded.resize(data.len() + n, 0);
+            }
+            Padding::AnsiX923 => {
+                padded.resize(data.len() + n - 1, 0);
+                padded.push(n as u8);
+            }
+            Padding::Zero => padded.resize(data.len().next_multiple_of(block_size), 0),
+        }
+        padded
+    }
+
+    /*
+    Removes the padding. The padding bytes are checked in constant time
+    and every malformed padding gives the same error, so the time taken
+    and the error returned do not reveal which byte was wrong.
+    */
+    pub fn unpad(&self, data: &[u8], block_size: usize) -> Result<Vec<u8>, CipherError> {
+        if !data.len().is_multiple_of(block_size) {
+            return Err(CipherError::InvalidBlockLengthError);
+        }
+        let length: usize = match self {
+            Padding::NoPadding => data.len(),
+            Padding::Zero => data.len() - data.iter().rev().take_while(|&&b| b == 0).count(),
+            Padding::Pkcs7 | Padding::AnsiX923 | Padding::Iso7816 => {
+                if data.is_empty() {
+                    return Err(CipherError::InvalidPaddingError);
+                }
+                let (padding_length, bad): (usize, u8) =
+                    self.check_padding(&data[data.len() - block_size..]);
+                if bad != 0 {
+                    return Err(CipherError::InvalidPaddingError);
+                }
+                data.len() - padding_length
+            }
+        };
+        Ok(data[..length].to_vec())
+    }
+
+    /*
+    Returns the padding length found in the last block and a byte that is
+    non-zero when the padding is malformed. Every byte of the block is
+    visited and no branch depends on the contents.
+    */
+    fn check_padding(&self, block: &[u8]) -> (usize, u8) {
+        let block_size: usize = block.len();
+        let mut bad: u8 = 0;
+
+        if *self == Padding::Iso7816 {
+            let mut found: u8 = 0;
+            let mut marker: u8 = 0;
+            let mut length: usize = 0;
+            for (i, &b) in block.iter().enumerate().rev() {
+                let first: u8 = !zero_mask(b) & !found;
+                marker |= first & b;
+                length |= usize::from(first & 1).wrapping_neg() & (block_size - i);
+                found = hint::black_box(found | !zero_mask(b));
+            }
+            bad |= (marker ^ 0x80) | !found;
+            return (length, bad);
+        }
+
+        let n: u8 = block[block_size - 1];
+        let length: usize = n as usize;
+        bad |= !le_mask(1, length) | !le_mask(length, block_size);
+        for (i, &b) in block.iter().enumerate() {
+            let position: usize = block_size - i;
+            let expected: u8 = match (self, position) {
+                (Padding::AnsiX923, 2..) => 0,
+                _ => n,
+            };
+            bad = hint::black_box(bad | (le_mask(position, length) & (b ^ expected)));
+        }
+        (length, bad)
+    }
+}
+
+/* 0xff when the byte is zero and 0x00 otherwise, without branching. */
+fn zero_mask(b: u8) -> u8 {
+    ((b as u16).wrapping_sub(1) >> 8) as u8
+}
+
+/* 0xff when a <= b and 0x00 otherwise, without branching, for values below 2^63. */
+fn le_mask(a: usize, b: usize) -> u8 {
+    (((b.wrapping_sub(a) >> (usize::BITS - 1)) as u8) ^ 1).wrapping_neg()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_padding_schemes() {
+        let tests = [
+            (Padding::Pkcs7, &b"abcde"[..], &b"abcde\x03\x03\x03"[..]),
+            (
+                Padding::Pkcs7,
+                b"abcdefgh",
+                b"abcdefgh\x08\x08\x08\x08\x08\x08\x08\x08",
+            ),
+            (Padding::Iso7816, b"abcde", b"abcde\x80\x00\x00"),
+            (Padding::Iso7816, b"abcdefg", b"abcdefg\x80"),
+            (Padding::AnsiX923, b"abcde", b"abcde\x00\x00\x03"),
+            (Padding::AnsiX923, b"", b"\x00\x00\x00\x00\x00\x00\x00\x08"),
+            (Padding::Zero, b"abcde", b"abcde\x00\x00\x00"),
+            (Padding::Zero, b"abcdefgh", b"abcdefgh"),
+            (Padding::NoPadding, b"abcdefgh", b"abcdefgh"),
+        ];
+
+        for (padding, data, expected) in tests {
+            assert_eq!(padding.pad(data, 8), expected);
+            assert_eq!(padding.unpad(expected, 8).unwrap(), data);
+        }
+    }
+
+    #[test]
+    fn test_invalid_padding() {
+        let tests = [
+            (Padding::Pkcs7, &b"abcde\x03\x02\x03"[..]),
+            (Padding::Pkcs7, b"abcdefg\x00"),
+            (Padding::Pkcs7, b"abcdefg\x09"),
+            (Padding::Pkcs7, b""),
+            (Padding::Iso7816, b"abcde\x00\x00\x00"),
+            (
+                Padding::Iso7816,
+                b"abcdefgh\x80\x00\x00\x00\x00\x00\x00\x00\x00",
+            ),
+            (Padding::AnsiX923, b"abcde\x01\x00\x03"),
+            (Padding::AnsiX923, b"abcdefg\x00"),
+            (Padding::AnsiX923, b"abcdefg\x09"),
+            (Padding::Iso7816, b"\x00\x00\x00\x00\x00\x00\x00\x00"),
+            (Padding::Iso7816, b"abcdefg\x81"),
+            (Padding::NoPadding, b"abcde"),
+        ];
+
+        for (padding, data) in tests {
+            assert!(padding.unpad(data, 8).is_err(), "{padding:?} {data:?}");
+        }
+
+        for padding in [Padding::Pkcs7, Padding::AnsiX923, Padding::Iso7816] {
+            for length in 0..8 {
+                let padded: Vec<u8> = padding.pad(&b"abcdefgh"[..length], 8);
+                assert_eq!(padding.unpad(&padded, 8).unwrap(), &b"abcdefgh"[..length]);
+                for i in length..7 {
+                    let mut corrupted: Vec<u8> = padded.clone();
+                    corrupted[i] ^= 0x01;
+                    assert!(matches!(
+                        padding.unpad(&corrupted, 8),
+                        Err(CipherError::InvalidPaddingError)
+                    ));
+                }
+            }
+        }
+    }
+}
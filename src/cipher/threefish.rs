@@ -0,0 +1,263 @@
+use crate::cipher::{BlockCipher, CipherError};
+use crate::secret;
+
+const C240: u64 = 0x1bd11bdaa9fc1a22;
+
+const TWEAK_LENGTH: usize = 16;
+
+const ROTATIONS_256: [[u32; 2]; 8] = [
+    [14, 16],
+    [52, 57],
+    [23, 40],
+    [5, 37],
+    [25, 33],
+    [46, 12],
+    [58, 22],
+    [32, 32],
+];
+
+const ROTATIONS_512: [[u32; 4]; 8] = [
+    [46, 36, 19, 37],
+    [33, 27, 14, 42],
+    [17, 49, 36, 39],
+    [44, 9, 54, 56],
+    [39, 30, 34, 24],
+    [13, 50, 10, 17],
+    [25, 29, 39, 43],
+    [8, 35, 56, 22],
+];
+
+const ROTATIONS_1024: [[u32; 8]; 8] = [
+    [24, 13, 8, 47, 8, 17, 22, 37],
+    [38, 19, 10, 55, 49, 18, 23, 52],
+    [33, 4, 51, 13, 34, 41, 59, 17],
+    [5, 20, 48, 41, 47, 28, 16, 25],
+    [41, 9, 37, 31, 12, 47, 44, 30],
+    [16, 34, 56, 51, 4, 53, 42, 41],
+    [31, 44, 47, 46, 19, 42, 44, 25],
+    [9, 48, 35, 52, 23, 31, 37, 20],
+];
+
+const PERMUTATION_256: [usize; 4] = [0, 3, 2, 1];
+const PERMUTATION_512: [usize; 8] = [2, 1, 4, 7, 6, 5, 0, 3];
+const PERMUTATION_1024: [usize; 16] = [0, 9, 2, 13, 6, 11, 4, 15, 10, 7, 12, 3, 14, 5, 8, 1];
+
+fn to_words(bytes: &[u8]) -> Vec<u64> {
+    bytes
+        .chunks_exact(8)
+        .map(|chunk| u64::from_le_bytes(chunk.try_into().unwrap()))
+        .collect()
+}
+
+fn to_bytes(words: &[u64]) -> Vec<u8> {
+    words.iter().flat_map(|word| word.to_le_bytes()).collect()
+}
+
+/*
+The Threefish tweakable block cipher from the Skein 1.3 specification, with
+block and key sizes of 256, 512 or 1024 bits and a 128-bit tweak. The block
+size is chosen by the length of the key.
+*/
+pub struct Threefish {
+    key: Vec<u64>,
+    tweak: [u64; 3],
+}
+
+impl Threefish {
+    pub fn new(key: &[u8], tweak: &[u8]) -> Result<Self, CipherError> {
+        if !matches!(key.len(), 32 | 64 | 128) {
+            return Err(CipherError::InvalidKeyLengthError);
+        }
+        if tweak.len() != TWEAK_LENGTH {
+            return Err(CipherError::InvalidTweakLengthError);
+        }
+        let tweak: Vec<u64> = to_words(tweak);
+        Ok(Self::from_words(&to_words(key), [tweak[0], tweak[1]]))
+    }
+
+    pub(crate) fn from_words(key: &[u64], tweak: [u64; 2]) -> Self {
+        let mut key: Vec<u64> = key.to_vec();
+        key.push(key.iter().fold(C240, |acc, word| acc ^ word));
+        Self {
+            key,
+            tweak: [tweak[0], tweak[1], tweak[0] ^ tweak[1]],
+        }
+    }
+
+    pub fn block_size(&self) -> usize {
+        self.words() * 8
+    }
+
+    pub fn encrypt_block(&self, block: &[u8]) -> Result<Vec<u8>, CipherError> {
+        if block.len() != self.block_size() {
+            return Err(CipherError::InvalidBlockLengthError);
+        }
+        Ok(to_bytes(&self.encrypt_words(&to_words(block))))
+    }
+
+    pub fn decrypt_block(&self, block: &[u8]) -> Result<Vec<u8>, CipherError> {
+        if block.len() != self.block_size() {
+            return Err(CipherError::InvalidBlockLengthError);
+        }
+        Ok(to_bytes(&self.decrypt_words(&to_words(block))))
+    }
+
+    fn words(&self) -> usize {
+        self.key.len() - 1
+    }
+
+    fn rounds(&self) -> usize {
+        match self.words() {
+            16 => 80,
+            _ => 72,
+        }
+    }
+
+    fn rotation(&self, d: usize, j: usize) -> u32 {
+        match self.words() {
+            4 => ROTATIONS_256[d % 8][j],
+            8 => ROTATIONS_512[d % 8][j],
+            _ => ROTATIONS_1024[d % 8][j],
+        }
+    }
+
+    fn permutation(&self) -> &'static [usize] {
+        match self.words() {
+            4 => &PERMUTATION_256,
+            8 => &PERMUTATION_512,
+            _ => &PERMUTATION_1024,
+        }
+    }
+
+    fn subkey(&self, s: usize) -> Vec<u64> {
+        let nw: usize = self.words();
+        let mut subkey: Vec<u64> = (0..nw).map(|i| self.key[(s + i) % (nw + 1)]).collect();
+        subkey[nw - 3] = subkey[nw - 3].wrapping_add(self.tweak[s % 3]);
+        subkey[nw - 2] = subkey[nw - 2].wrapping_add(self.tweak[(s + 1) % 3]);
+        subkey[nw - 1] = subkey[nw - 1].wrapping_add(s as u64);
+        subkey
+    }
+
+    pub(crate) fn encrypt_words(&self, block: &[u64]) -> Vec<u64> {
+        let nw: usize = self.words();
+        let permutation: &[usize] = self.permutation();
+        let mut v: Vec<u64> = block.to_vec();
+
+        for d in 0..self.rounds() {
+            if d % 4 == 0 {
+                for (word, k) in v.iter_mut().zip(self.subkey(d / 4)) {
+                    *word = word.wrapping_add(k);
+                }
+            }
+            let mut f: Vec<u64> = Vec::with_capacity(nw);
+            for j in 0..nw / 2 {
+                let y0: u64 = v[2 * j].wrapping_add(v[2 * j + 1]);
+                let y1: u64 = v[2 * j + 1].rotate_left(self.rotation(d, j)) ^ y0;
+                f.push(y0);
+                f.push(y1);
+            }
+            v = permutation.iter().map(|&i| f[i]).collect();
+        }
+        for (word, k) in v.iter_mut().zip(self.subkey(self.rounds() / 4)) {
+            *word = word.wrapping_add(k);
+        }
+        v
+    }
+
+    fn decrypt_words(&self, block: &[u64]) -> Vec<u64> {
+        let nw: usize = self.words();
+        let permutation: &[usize] = self.permutation();
+        let mut v: Vec<u64> = block.to_vec();
+
+        for (word, k) in v.iter_mut().zip(self.subkey(self.rounds() / 4)) {
+            *word = word.wrapping_sub(k);
+        }
+        for d in (0..self.rounds()).rev() {
+            let mut f: Vec<u64> = vec![0; nw];
+            for (i, &p) in permutation.iter().enumerate() {
+                f[p] = v[i];
+            }
+            for j in 0..nw / 2 {
+                let x1: u64 = (f[2 * j + 1] ^ f[2 * j]).rotate_right(self.rotation(d, j));
+                v[2 * j] = f[2 * j].wrapping_sub(x1);
+                v[2 * j + 1] = x1;
+            }
+            if d % 4 == 0 {
+                for (word, k) in v.iter_mut().zip(self.subkey(d / 4)) {
+                    *word = word.wrapping_sub(k);
+                }
+            }
+        }
+        v
+    }
+}
+
+impl BlockCipher for Threefish {
+    fn block_size(&self) -> usize {
+        Threefish::block_size(self)
+    }
+
+    fn encrypt_block(&self, block: &[u8]) -> Result<Vec<u8>, CipherError> {
+        Threefish::encrypt_block(self, block)
+    }
+
+    fn decrypt_block(&self, block: &[u8]) -> Result<Vec<u8>, CipherError> {
+        Threefish::decrypt_block(self, block)
+    }
+}
+
+impl Drop for Threefish {
+    fn drop(&mut self) {
+        secret::zeroize(&mut self.key);
+        secret::zeroize_value(&mut self.tweak);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hash::Message;
+
+    #[test]
+    fn test_threefish_on_zero_vectors() {
+        let tests = [
+            (32, "84da2a1f8beaee947066ae3e3103f1ad536db1f4a1192495116b9f3ce6133fd8"),
+            (64, "b1a2bbc6ef6025bc40eb3822161f36e375d1bb0aee3186fbd19e47c5d479947b7bc2f8586e35f0cff7e7f03084b0b7b1f1ab3961a580a3e97eb41ea14a6d7bbe"),
+            (128, "f05c3d0a3d05b304f785ddc7d1e036015c8aa76e2f217b06c6e1544c0bc1a90df0accb9473c24e0fd54fea68057f43329cb454761d6df5cf7b2e9b3614fbd5a20b2e4760b40603540d82eabc5482c171c832afbe68406bc39500367a592943fa9a5b4a43286ca3c4cf46104b443143d560a4b230488311df4feef7e1dfe8391e"),
+        ];
+
+        for (size, expected) in tests {
+            let threefish = Threefish::new(&vec![0; size], &[0; 16]).unwrap();
+            let ciphertext = threefish.encrypt_block(&vec![0; size]).unwrap();
+            assert_eq!(ciphertext, Message::from_hex(expected).unwrap().to_slice());
+            assert_eq!(threefish.decrypt_block(&ciphertext).unwrap(), vec![0; size]);
+        }
+    }
+
+    #[test]
+    fn test_threefish_on_counting_vectors() {
+        let tests = [
+            (32, "e0d091ff0eea8fdfc98192e62ed80ad59d865d08588df476657056b5955e97df"),
+            (64, "e304439626d45a2cb401cad8d636249a6338330eb06d45dd8b36b90e97254779272a0a8d99463504784420ea18c9a725af11dffea10162348927673d5c1caf3d"),
+            (128, "a6654ddbd73cc3b05dd777105aa849bce49372eaaffc5568d254771bab85531c94f780e7ffaae430d5d8af8c70eebbe1760f3b42b737a89cb363490d670314bd8aa41ee63c2e1f45fbd477922f8360b388d6125ea6c7af0ad7056d01796e90c83313f4150a5716b30ed5f569288ae974ce2b4347926fce57de44512177dd7cde"),
+        ];
+        let tweak: Vec<u8> = (0x00..0x10).collect();
+
+        for (size, expected) in tests {
+            let key: Vec<u8> = (0..size).map(|i| 0x10 + i as u8).collect();
+            let plaintext: Vec<u8> = (0..size).map(|i| 0xff - i as u8).collect();
+            let threefish = Threefish::new(&key, &tweak).unwrap();
+            let ciphertext = threefish.encrypt_block(&plaintext).unwrap();
+            assert_eq!(ciphertext, Message::from_hex(expected).unwrap().to_slice());
+            assert_eq!(threefish.decrypt_block(&ciphertext).unwrap(), plaintext);
+        }
+    }
+
+    #[test]
+    fn test_threefish_invalid_lengths() {
+        assert!(Threefish::new(&[0; 16], &[0; 16]).is_err());
+        assert!(Threefish::new(&[0; 32], &[0; 8]).is_err());
+        let threefish = Threefish::new(&[0; 32], &[0; 16]).unwrap();
+        assert!(threefish.encrypt_block(&[0; 64]).is_err());
+    }
+}
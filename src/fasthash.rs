@@ -0,0 +1,3 @@
+pub mod fnv;
+pub mod murmur3;
+pub mod xxhash;
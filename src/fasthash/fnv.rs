@@ -0,0 +1,55 @@
+use crate::hash::{Digest, Endianness, Message};
+
+const FNV32_OFFSET_BASIS: u32 = 0x811c9dc5;
+const FNV32_PRIME: u32 = 0x01000193;
+
+const FNV64_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV64_PRIME: u64 = 0x00000100000001b3;
+
+pub struct FNV1a32;
+
+impl FNV1a32 {
+    pub fn hash(&self, input: &Message) -> Digest {
+        let h: u32 = input
+            .to_slice()
+            .iter()
+            .fold(FNV32_OFFSET_BASIS, |h, &byte| {
+                (h ^ byte as u32).wrapping_mul(FNV32_PRIME)
+            });
+        Digest::from_u32(&[h], Endianness::Big)
+    }
+}
+
+pub struct FNV1a64;
+
+impl FNV1a64 {
+    pub fn hash(&self, input: &Message) -> Digest {
+        let h: u64 = input
+            .to_slice()
+            .iter()
+            .fold(FNV64_OFFSET_BASIS, |h, &byte| {
+                (h ^ byte as u64).wrapping_mul(FNV64_PRIME)
+            });
+        Digest::from_u64(&[h], Endianness::Big)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fnv1a() {
+        let tests = [
+            ("", "811c9dc5", "cbf29ce484222325"),
+            ("a", "e40c292c", "af63dc4c8601ec8c"),
+            ("foobar", "bf9cf968", "85944171f73967e8"),
+        ];
+
+        for (message, expected_32, expected_64) in tests {
+            let input = Message::from_string(message);
+            assert_eq!(FNV1a32.hash(&input).to_hex(), expected_32);
+            assert_eq!(FNV1a64.hash(&input).to_hex(), expected_64);
+        }
+    }
+}
@@ -0,0 +1,187 @@
+use crate::hash::{Digest, Endianness, Message};
+
+const X86_32_C1: u32 = 0xcc9e2d51;
+const X86_32_C2: u32 = 0x1b873593;
+
+const X64_128_C1: u64 = 0x87c37b91114253d5;
+const X64_128_C2: u64 = 0x4cf5ad432745937f;
+
+fn fmix32(mut h: u32) -> u32 {
+    h ^= h >> 16;
+    h = h.wrapping_mul(0x85ebca6b);
+    h ^= h >> 13;
+    h = h.wrapping_mul(0xc2b2ae35);
+    h ^ (h >> 16)
+}
+
+fn fmix64(mut k: u64) -> u64 {
+    k ^= k >> 33;
+    k = k.wrapping_mul(0xff51afd7ed558ccd);
+    k ^= k >> 33;
+    k = k.wrapping_mul(0xc4ceb9fe1a85ec53);
+    k ^ (k >> 33)
+}
+
+fn read_le_u64(bytes: &[u8]) -> u64 {
+    let mut buffer: [u8; 8] = [0; 8];
+    buffer[..bytes.len()].copy_from_slice(bytes);
+    u64::from_le_bytes(buffer)
+}
+
+pub struct Murmur3X86_32 {
+    seed: u32,
+}
+
+impl Murmur3X86_32 {
+    pub fn new(seed: u32) -> Self {
+        Self { seed }
+    }
+
+    pub fn hash(&self, input: &Message) -> Digest {
+        let input: &[u8] = input.to_slice();
+        let mut h1: u32 = self.seed;
+
+        let blocks = input.chunks_exact(4);
+        let tail: &[u8] = blocks.remainder();
+        for block in blocks {
+            let k1: u32 = u32::from_le_bytes(block.try_into().unwrap())
+                .wrapping_mul(X86_32_C1)
+                .rotate_left(15)
+                .wrapping_mul(X86_32_C2);
+            h1 = (h1 ^ k1)
+                .rotate_left(13)
+                .wrapping_mul(5)
+                .wrapping_add(0xe6546b64);
+        }
+
+        if !tail.is_empty() {
+            let k1: u32 = (read_le_u64(tail) as u32)
+                .wrapping_mul(X86_32_C1)
+                .rotate_left(15)
+                .wrapping_mul(X86_32_C2);
+            h1 ^= k1;
+        }
+
+        h1 = fmix32(h1 ^ input.len() as u32);
+        Digest::from_u32(&[h1], Endianness::Big)
+    }
+}
+
+/*
+The two 64-bit halves of the result are returned in order, each one
+written as a big-endian integer, which is how the value is usually
+displayed.
+*/
+pub struct Murmur3X64_128 {
+    seed: u32,
+}
+
+impl Murmur3X64_128 {
+    pub fn new(seed: u32) -> Self {
+        Self { seed }
+    }
+
+    pub fn hash(&self, input: &Message) -> Digest {
+        let input: &[u8] = input.to_slice();
+        let mut h1: u64 = self.seed as u64;
+        let mut h2: u64 = self.seed as u64;
+
+        let blocks = input.chunks_exact(16);
+        let tail: &[u8] = blocks.remainder();
+        for block in blocks {
+            let k1: u64 = read_le_u64(&block[..8])
+                .wrapping_mul(X64_128_C1)
+                .rotate_left(31)
+                .wrapping_mul(X64_128_C2);
+            h1 = (h1 ^ k1)
+                .rotate_left(27)
+                .wrapping_add(h2)
+                .wrapping_mul(5)
+                .wrapping_add(0x52dce729);
+
+            let k2: u64 = read_le_u64(&block[8..])
+                .wrapping_mul(X64_128_C2)
+                .rotate_left(33)
+                .wrapping_mul(X64_128_C1);
+            h2 = (h2 ^ k2)
+                .rotate_left(31)
+                .wrapping_add(h1)
+                .wrapping_mul(5)
+                .wrapping_add(0x38495ab5);
+        }
+
+        if tail.len() > 8 {
+            let k2: u64 = read_le_u64(&tail[8..])
+                .wrapping_mul(X64_128_C2)
+                .rotate_left(33)
+                .wrapping_mul(X64_128_C1);
+            h2 ^= k2;
+        }
+        if !tail.is_empty() {
+            let k1: u64 = read_le_u64(&tail[..tail.len().min(8)])
+                .wrapping_mul(X64_128_C1)
+                .rotate_left(31)
+                .wrapping_mul(X64_128_C2);
+            h1 ^= k1;
+        }
+
+        h1 ^= input.len() as u64;
+        h2 ^= input.len() as u64;
+        h1 = h1.wrapping_add(h2);
+        h2 = h2.wrapping_add(h1);
+        h1 = fmix64(h1);
+        h2 = fmix64(h2);
+        h1 = h1.wrapping_add(h2);
+        h2 = h2.wrapping_add(h1);
+        Digest::from_u64(&[h1, h2], Endianness::Big)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_murmur3_x86_32() {
+        let tests = [
+            ("", 0, "00000000"),
+            ("", 42, "087fcd5c"),
+            ("hello", 0, "248bfa47"),
+            ("hello", 42, "e2dbd2e1"),
+            ("The quick brown fox jumps over the lazy dog", 0, "2e4ff723"),
+        ];
+
+        for (message, seed, expected) in tests {
+            assert_eq!(
+                Murmur3X86_32::new(seed)
+                    .hash(&Message::from_string(message))
+                    .to_hex(),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_murmur3_x64_128() {
+        let tests = [
+            ("", 0, "00000000000000000000000000000000"),
+            ("", 42, "f02aa77dfa1b8523d1016610da11cbb9"),
+            ("hello", 0, "cbd8a7b341bd9b025b1e906a48ae1d19"),
+            ("hello", 42, "c4b8b3c960af6f082334b875b0efbc7a"),
+            (
+                "The quick brown fox jumps over the lazy dog",
+                0,
+                "e34bbc7bbc071b6c7a433ca9c49a9347",
+            ),
+        ];
+
+        for (message, seed, expected) in tests {
+            assert_eq!(
+                Murmur3X64_128::new(seed)
+                    .hash(&Message::from_string(message))
+                    .to_hex(),
+                expected
+            );
+        }
+    }
+}
@@ -0,0 +1,569 @@
+use crate::hash::{Digest, Endianness, Message};
+
+const PRIME32_1: u64 = 0x9e3779b1;
+const PRIME32_2: u64 = 0x85ebca77;
+const PRIME32_3: u64 = 0xc2b2ae3d;
+
+const PRIME64_1: u64 = 0x9e3779b185ebca87;
+const PRIME64_2: u64 = 0xc2b2ae3d27d4eb4f;
+const PRIME64_3: u64 = 0x165667b19e3779f9;
+const PRIME64_4: u64 = 0x85ebca77c2b2ae63;
+const PRIME64_5: u64 = 0x27d4eb2f165667c5;
+
+const PRIME_MX1: u64 = 0x165667919e3779f9;
+const PRIME_MX2: u64 = 0x9fb21c651e98df25;
+
+const STRIPE_LENGTH: usize = 64;
+const SECRET_CONSUME_RATE: usize = 8;
+const MIDSIZE_START_OFFSET: usize = 3;
+const MIDSIZE_LAST_OFFSET: usize = 17;
+const SECRET_SIZE_MIN: usize = 136;
+
+const DEFAULT_SECRET: [u8; 192] = [
+    0xb8, 0xfe, 0x6c, 0x39, 0x23, 0xa4, 0x4b, 0xbe, 0x7c, 0x01, 0x81, 0x2c, 0xf7, 0x21, 0xad, 0x1c,
+    0xde, 0xd4, 0x6d, 0xe9, 0x83, 0x90, 0x97, 0xdb, 0x72, 0x40, 0xa4, 0xa4, 0xb7, 0xb3, 0x67, 0x1f,
+    0xcb, 0x79, 0xe6, 0x4e, 0xcc, 0xc0, 0xe5, 0x78, 0x82, 0x5a, 0xd0, 0x7d, 0xcc, 0xff, 0x72, 0x21,
+    0xb8, 0x08, 0x46, 0x74, 0xf7, 0x43, 0x24, 0x8e, 0xe0, 0x35, 0x90, 0xe6, 0x81, 0x3a, 0x26, 0x4c,
+    0x3c, 0x28, 0x52, 0xbb, 0x91, 0xc3, 0x00, 0xcb, 0x88, 0xd0, 0x65, 0x8b, 0x1b, 0x53, 0x2e, 0xa3,
+    0x71, 0x64, 0x48, 0x97, 0xa2, 0x0d, 0xf9, 0x4e, 0x38, 0x19, 0xef, 0x46, 0xa9, 0xde, 0xac, 0xd8,
+    0xa8, 0xfa, 0x76, 0x3f, 0xe3, 0x9c, 0x34, 0x3f, 0xf9, 0xdc, 0xbb, 0xc7, 0xc7, 0x0b, 0x4f, 0x1d,
+    0x8a, 0x51, 0xe0, 0x4b, 0xcd, 0xb4, 0x59, 0x31, 0xc8, 0x9f, 0x7e, 0xc9, 0xd9, 0x78, 0x73, 0x64,
+    0xea, 0xc5, 0xac, 0x83, 0x34, 0xd3, 0xeb, 0xc3, 0xc5, 0x81, 0xa0, 0xff, 0xfa, 0x13, 0x63, 0xeb,
+    0x17, 0x0d, 0xdd, 0x51, 0xb7, 0xf0, 0xda, 0x49, 0xd3, 0x16, 0x55, 0x26, 0x29, 0xd4, 0x68, 0x9e,
+    0x2b, 0x16, 0xbe, 0x58, 0x7d, 0x47, 0xa1, 0xfc, 0x8f, 0xf8, 0xb8, 0xd1, 0x7a, 0xd0, 0x31, 0xce,
+    0x45, 0xcb, 0x3a, 0x8f, 0x95, 0x16, 0x04, 0x28, 0xaf, 0xd7, 0xfb, 0xca, 0xbb, 0x4b, 0x40, 0x7e,
+];
+
+fn read_u32(bytes: &[u8], offset: usize) -> u64 {
+    u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap()) as u64
+}
+
+fn read_u64(bytes: &[u8], offset: usize) -> u64 {
+    u64::from_le_bytes(bytes[offset..offset + 8].try_into().unwrap())
+}
+
+fn mul128_fold64(a: u64, b: u64) -> u64 {
+    let product: u128 = a as u128 * b as u128;
+    (product as u64) ^ ((product >> 64) as u64)
+}
+
+fn xxh64_round(acc: u64, lane: u64) -> u64 {
+    acc.wrapping_add(lane.wrapping_mul(PRIME64_2))
+        .rotate_left(31)
+        .wrapping_mul(PRIME64_1)
+}
+
+fn xxh64_merge_round(acc: u64, value: u64) -> u64 {
+    (acc ^ xxh64_round(0, value))
+        .wrapping_mul(PRIME64_1)
+        .wrapping_add(PRIME64_4)
+}
+
+fn xxh64_avalanche(mut h: u64) -> u64 {
+    h ^= h >> 33;
+    h = h.wrapping_mul(PRIME64_2);
+    h ^= h >> 29;
+    h = h.wrapping_mul(PRIME64_3);
+    h ^ (h >> 32)
+}
+
+fn xxh3_avalanche(mut h: u64) -> u64 {
+    h ^= h >> 37;
+    h = h.wrapping_mul(PRIME_MX1);
+    h ^ (h >> 32)
+}
+
+fn rrmxmx(mut h: u64, length: usize) -> u64 {
+    h ^= h.rotate_left(49) ^ h.rotate_left(24);
+    h = h.wrapping_mul(PRIME_MX2);
+    h ^= (h >> 35).wrapping_add(length as u64);
+    h = h.wrapping_mul(PRIME_MX2);
+    h ^ (h >> 28)
+}
+
+pub struct XXH64 {
+    seed: u64,
+}
+
+impl XXH64 {
+    pub fn new(seed: u64) -> Self {
+        Self { seed }
+    }
+
+    pub fn hash(&self, input: &Message) -> Digest {
+        let input: &[u8] = input.to_slice();
+        let mut h: u64;
+
+        let stripes = input.chunks_exact(32);
+        let mut tail: &[u8] = stripes.remainder();
+        if input.len() >= 32 {
+            let mut v: [u64; 4] = [
+                self.seed.wrapping_add(PRIME64_1).wrapping_add(PRIME64_2),
+                self.seed.wrapping_add(PRIME64_2),
+                self.seed,
+                self.seed.wrapping_sub(PRIME64_1),
+            ];
+            for stripe in stripes {
+                for (i, v) in v.iter_mut().enumerate() {
+                    *v = xxh64_round(*v, read_u64(stripe, 8 * i));
+                }
+            }
+            h = v[0]
+                .rotate_left(1)
+                .wrapping_add(v[1].rotate_left(7))
+                .wrapping_add(v[2].rotate_left(12))
+                .wrapping_add(v[3].rotate_left(18));
+            for v in v {
+                h = xxh64_merge_round(h, v);
+            }
+        } else {
+            h = self.seed.wrapping_add(PRIME64_5);
+        }
+        h = h.wrapping_add(input.len() as u64);
+
+        while tail.len() >= 8 {
+            h ^= xxh64_round(0, read_u64(tail, 0));
+            h = h
+                .rotate_left(27)
+                .wrapping_mul(PRIME64_1)
+                .wrapping_add(PRIME64_4);
+            tail = &tail[8..];
+        }
+        if tail.len() >= 4 {
+            h ^= read_u32(tail, 0).wrapping_mul(PRIME64_1);
+            h = h
+                .rotate_left(23)
+                .wrapping_mul(PRIME64_2)
+                .wrapping_add(PRIME64_3);
+            tail = &tail[4..];
+        }
+        for &byte in tail {
+            h ^= (byte as u64).wrapping_mul(PRIME64_5);
+            h = h.rotate_left(11).wrapping_mul(PRIME64_1);
+        }
+
+        Digest::from_u64(&[xxh64_avalanche(h)], Endianness::Big)
+    }
+}
+
+/*
+Derives the secret used for long inputs by adding the seed to the first
+half of every 16-byte lane of the default secret and subtracting it from
+the second half. A zero seed leaves the default secret unchanged.
+*/
+fn derive_secret(seed: u64) -> [u8; 192] {
+    let mut secret: [u8; 192] = DEFAULT_SECRET;
+    for lane in secret.chunks_exact_mut(16) {
+        let lo: u64 = read_u64(lane, 0).wrapping_add(seed);
+        let hi: u64 = read_u64(lane, 8).wrapping_sub(seed);
+        lane[..8].copy_from_slice(&lo.to_le_bytes());
+        lane[8..].copy_from_slice(&hi.to_le_bytes());
+    }
+    secret
+}
+
+fn mix16(input: &[u8], secret: &[u8], seed: u64) -> u64 {
+    mul128_fold64(
+        read_u64(input, 0) ^ read_u64(secret, 0).wrapping_add(seed),
+        read_u64(input, 8) ^ read_u64(secret, 8).wrapping_sub(seed),
+    )
+}
+
+fn mix32(acc: (u64, u64), input_1: &[u8], input_2: &[u8], secret: &[u8], seed: u64) -> (u64, u64) {
+    let mut lo: u64 = acc.0.wrapping_add(mix16(input_1, secret, seed));
+    lo ^= read_u64(input_2, 0).wrapping_add(read_u64(input_2, 8));
+    let mut hi: u64 = acc.1.wrapping_add(mix16(input_2, &secret[16..], seed));
+    hi ^= read_u64(input_1, 0).wrapping_add(read_u64(input_1, 8));
+    (lo, hi)
+}
+
+fn accumulate_512(acc: &mut [u64; 8], stripe: &[u8], secret: &[u8]) {
+    for i in 0..8 {
+        let data: u64 = read_u64(stripe, 8 * i);
+        let key: u64 = data ^ read_u64(secret, 8 * i);
+        acc[i ^ 1] = acc[i ^ 1].wrapping_add(data);
+        acc[i] = acc[i].wrapping_add((key & 0xffffffff).wrapping_mul(key >> 32));
+    }
+}
+
+fn scramble(acc: &mut [u64; 8], secret: &[u8]) {
+    for (i, acc) in acc.iter_mut().enumerate() {
+        *acc ^= *acc >> 47;
+        *acc ^= read_u64(secret, 8 * i);
+        *acc = acc.wrapping_mul(PRIME32_1);
+    }
+}
+
+fn merge_accumulators(acc: &[u64; 8], secret: &[u8], start: u64) -> u64 {
+    let result: u64 = (0..4).fold(start, |result, i| {
+        result.wrapping_add(mul128_fold64(
+            acc[2 * i] ^ read_u64(secret, 16 * i),
+            acc[2 * i + 1] ^ read_u64(secret, 16 * i + 8),
+        ))
+    });
+    xxh3_avalanche(result)
+}
+
+/*
+Processes inputs longer than 240 bytes. The input is split into blocks
+of stripes, each stripe being mixed with a different part of the secret,
+and the accumulators are scrambled at the end of every block.
+*/
+fn hash_long(input: &[u8], secret: &[u8]) -> [u64; 8] {
+    let mut acc: [u64; 8] = [
+        PRIME32_3, PRIME64_1, PRIME64_2, PRIME64_3, PRIME64_4, PRIME32_2, PRIME64_5, PRIME32_1,
+    ];
+    let stripes_per_block: usize = (secret.len() - STRIPE_LENGTH) / SECRET_CONSUME_RATE;
+    let block_length: usize = STRIPE_LENGTH * stripes_per_block;
+    let num_blocks: usize = (input.len() - 1) / block_length;
+
+    for block in input.chunks_exact(block_length).take(num_blocks) {
+        for (s, stripe) in block.chunks_exact(STRIPE_LENGTH).enumerate() {
+            accumulate_512(&mut acc, stripe, &secret[s * SECRET_CONSUME_RATE..]);
+        }
+        scramble(&mut acc, &secret[secret.len() - STRIPE_LENGTH..]);
+    }
+
+    let last_block: &[u8] = &input[num_blocks * block_length..];
+    let num_stripes: usize = (last_block.len() - 1) / STRIPE_LENGTH;
+    for (s, stripe) in last_block
+        .chunks_exact(STRIPE_LENGTH)
+        .take(num_stripes)
+        .enumerate()
+    {
+        accumulate_512(&mut acc, stripe, &secret[s * SECRET_CONSUME_RATE..]);
+    }
+    accumulate_512(
+        &mut acc,
+        &input[input.len() - STRIPE_LENGTH..],
+        &secret[secret.len() - STRIPE_LENGTH - 7..],
+    );
+    acc
+}
+
+pub struct XXH3_64 {
+    seed: u64,
+}
+
+impl XXH3_64 {
+    pub fn new(seed: u64) -> Self {
+        Self { seed }
+    }
+
+    pub fn hash(&self, input: &Message) -> Digest {
+        let input: &[u8] = input.to_slice();
+        let secret: &[u8] = &DEFAULT_SECRET;
+        let seed: u64 = self.seed;
+        let length: usize = input.len();
+
+        let h: u64 = match length {
+            0 => xxh64_avalanche(seed ^ read_u64(secret, 56) ^ read_u64(secret, 64)),
+            1..=3 => {
+                let combined: u64 = ((input[0] as u64) << 16)
+                    | ((input[length >> 1] as u64) << 24)
+                    | (input[length - 1] as u64)
+                    | ((length as u64) << 8);
+                let bitflip: u64 = (read_u32(secret, 0) ^ read_u32(secret, 4)).wrapping_add(seed);
+                xxh64_avalanche(combined ^ bitflip)
+            }
+            4..=8 => {
+                let seed: u64 = seed ^ (((seed as u32).swap_bytes() as u64) << 32);
+                let bitflip: u64 = (read_u64(secret, 8) ^ read_u64(secret, 16)).wrapping_sub(seed);
+                let combined: u64 = read_u32(input, length - 4) | (read_u32(input, 0) << 32);
+                rrmxmx(combined ^ bitflip, length)
+            }
+            9..=16 => {
+                let bitflip_1: u64 =
+                    (read_u64(secret, 24) ^ read_u64(secret, 32)).wrapping_add(seed);
+                let bitflip_2: u64 =
+                    (read_u64(secret, 40) ^ read_u64(secret, 48)).wrapping_sub(seed);
+                let lo: u64 = read_u64(input, 0) ^ bitflip_1;
+                let hi: u64 = read_u64(input, length - 8) ^ bitflip_2;
+                xxh3_avalanche(
+                    (length as u64)
+                        .wrapping_add(lo.swap_bytes())
+                        .wrapping_add(hi)
+                        .wrapping_add(mul128_fold64(lo, hi)),
+                )
+            }
+            17..=128 => {
+                let mut acc: u64 = (length as u64).wrapping_mul(PRIME64_1);
+                let num_rounds: usize = (length - 1) / 32;
+                for i in 0..=num_rounds {
+                    acc = acc
+                        .wrapping_add(mix16(&input[16 * i..], &secret[32 * i..], seed))
+                        .wrapping_add(mix16(
+                            &input[length - 16 * (i + 1)..],
+                            &secret[32 * i + 16..],
+                            seed,
+                        ));
+                }
+                xxh3_avalanche(acc)
+            }
+            129..=240 => {
+                let mut acc: u64 = (length as u64).wrapping_mul(PRIME64_1);
+                for i in 0..8 {
+                    acc = acc.wrapping_add(mix16(&input[16 * i..], &secret[16 * i..], seed));
+                }
+                acc = xxh3_avalanche(acc);
+                for i in 8..length / 16 {
+                    acc = acc.wrapping_add(mix16(
+                        &input[16 * i..],
+                        &secret[16 * (i - 8) + MIDSIZE_START_OFFSET..],
+                        seed,
+                    ));
+                }
+                acc = acc.wrapping_add(mix16(
+                    &input[length - 16..],
+                    &secret[SECRET_SIZE_MIN - MIDSIZE_LAST_OFFSET..],
+                    seed,
+                ));
+                xxh3_avalanche(acc)
+            }
+            _ => {
+                let secret: [u8; 192] = derive_secret(seed);
+                let acc: [u64; 8] = hash_long(input, &secret);
+                merge_accumulators(&acc, &secret[11..], (length as u64).wrapping_mul(PRIME64_1))
+            }
+        };
+        Digest::from_u64(&[h], Endianness::Big)
+    }
+}
+
+/*
+The 128-bit result is returned in its canonical form, the high 64 bits
+followed by the low 64 bits, both big-endian.
+*/
+pub struct XXH3_128 {
+    seed: u64,
+}
+
+impl XXH3_128 {
+    pub fn new(seed: u64) -> Self {
+        Self { seed }
+    }
+
+    pub fn hash(&self, input: &Message) -> Digest {
+        let input: &[u8] = input.to_slice();
+        let secret: &[u8] = &DEFAULT_SECRET;
+        let seed: u64 = self.seed;
+        let length: usize = input.len();
+
+        let (lo, hi): (u64, u64) = match length {
+            0 => (
+                xxh64_avalanche(seed ^ read_u64(secret, 64) ^ read_u64(secret, 72)),
+                xxh64_avalanche(seed ^ read_u64(secret, 80) ^ read_u64(secret, 88)),
+            ),
+            1..=3 => {
+                let combined_lo: u32 = ((input[0] as u32) << 16)
+                    | ((input[length >> 1] as u32) << 24)
+                    | (input[length - 1] as u32)
+                    | ((length as u32) << 8);
+                let combined_hi: u32 = combined_lo.swap_bytes().rotate_left(13);
+                let bitflip_lo: u64 =
+                    (read_u32(secret, 0) ^ read_u32(secret, 4)).wrapping_add(seed);
+                let bitflip_hi: u64 =
+                    (read_u32(secret, 8) ^ read_u32(secret, 12)).wrapping_sub(seed);
+                (
+                    xxh64_avalanche(combined_lo as u64 ^ bitflip_lo),
+                    xxh64_avalanche(combined_hi as u64 ^ bitflip_hi),
+                )
+            }
+            4..=8 => {
+                let seed: u64 = seed ^ (((seed as u32).swap_bytes() as u64) << 32);
+                let combined: u64 = read_u32(input, 0) | (read_u32(input, length - 4) << 32);
+                let bitflip: u64 = (read_u64(secret, 16) ^ read_u64(secret, 24)).wrapping_add(seed);
+                let product: u128 = (combined ^ bitflip) as u128
+                    * PRIME64_1.wrapping_add((length as u64) << 2) as u128;
+                let mut lo: u64 = product as u64;
+                let mut hi: u64 = (product >> 64) as u64;
+                hi = hi.wrapping_add(lo << 1);
+                lo ^= hi >> 3;
+                lo ^= lo >> 35;
+                lo = lo.wrapping_mul(PRIME_MX2);
+                lo ^= lo >> 28;
+                (lo, xxh3_avalanche(hi))
+            }
+            9..=16 => {
+                let bitflip_lo: u64 =
+                    (read_u64(secret, 32) ^ read_u64(secret, 40)).wrapping_sub(seed);
+                let bitflip_hi: u64 =
+                    (read_u64(secret, 48) ^ read_u64(secret, 56)).wrapping_add(seed);
+                let input_lo: u64 = read_u64(input, 0);
+                let input_hi: u64 = read_u64(input, length - 8) ^ bitflip_hi;
+                let product: u128 =
+                    ((input_lo ^ input_hi ^ bitflip_hi ^ bitflip_lo) as u128) * PRIME64_1 as u128;
+                let mut lo: u64 = (product as u64).wrapping_add(((length - 1) as u64) << 54);
+                let hi: u64 = ((product >> 64) as u64)
+                    .wrapping_add(input_hi)
+                    .wrapping_add((input_hi & 0xffffffff).wrapping_mul(PRIME32_2 - 1));
+                lo ^= hi.swap_bytes();
+                let product: u128 = lo as u128 * PRIME64_2 as u128;
+                (
+                    xxh3_avalanche(product as u64),
+                    xxh3_avalanche(
+                        ((product >> 64) as u64).wrapping_add(hi.wrapping_mul(PRIME64_2)),
+                    ),
+                )
+            }
+            17..=128 => {
+                let mut acc: (u64, u64) = ((length as u64).wrapping_mul(PRIME64_1), 0);
+                let num_rounds: usize = (length - 1) / 32;
+                for i in (0..=num_rounds).rev() {
+                    acc = mix32(
+                        acc,
+                        &input[16 * i..],
+                        &input[length - 16 * (i + 1)..],
+                        &secret[32 * i..],
+                        seed,
+                    );
+                }
+                finalize_128(acc, length, seed)
+            }
+            129..=240 => {
+                let mut acc: (u64, u64) = ((length as u64).wrapping_mul(PRIME64_1), 0);
+                for i in 0..4 {
+                    acc = mix32(
+                        acc,
+                        &input[32 * i..],
+                        &input[32 * i + 16..],
+                        &secret[32 * i..],
+                        seed,
+                    );
+                }
+                acc = (xxh3_avalanche(acc.0), xxh3_avalanche(acc.1));
+                for i in 4..length / 32 {
+                    acc = mix32(
+                        acc,
+                        &input[32 * i..],
+                        &input[32 * i + 16..],
+                        &secret[32 * (i - 4) + MIDSIZE_START_OFFSET..],
+                        seed,
+                    );
+                }
+                acc = mix32(
+                    acc,
+                    &input[length - 16..],
+                    &input[length - 32..],
+                    &secret[SECRET_SIZE_MIN - MIDSIZE_LAST_OFFSET - 16..],
+                    0u64.wrapping_sub(seed),
+                );
+                finalize_128(acc, length, seed)
+            }
+            _ => {
+                let secret: [u8; 192] = derive_secret(seed);
+                let acc: [u64; 8] = hash_long(input, &secret);
+                (
+                    merge_accumulators(
+                        &acc,
+                        &secret[11..],
+                        (length as u64).wrapping_mul(PRIME64_1),
+                    ),
+                    merge_accumulators(
+                        &acc,
+                        &secret[secret.len() - STRIPE_LENGTH - 11..],
+                        !(length as u64).wrapping_mul(PRIME64_2),
+                    ),
+                )
+            }
+        };
+        Digest::from_u64(&[hi, lo], Endianness::Big)
+    }
+}
+
+fn finalize_128(acc: (u64, u64), length: usize, seed: u64) -> (u64, u64) {
+    let lo: u64 = acc.0.wrapping_add(acc.1);
+    let hi: u64 = acc
+        .0
+        .wrapping_mul(PRIME64_1)
+        .wrapping_add(acc.1.wrapping_mul(PRIME64_4))
+        .wrapping_add((length as u64).wrapping_sub(seed).wrapping_mul(PRIME64_2));
+    (xxh3_avalanche(lo), 0u64.wrapping_sub(xxh3_avalanche(hi)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FOX: &str = "The quick brown fox jumps over the lazy dog";
+
+    fn cycle(length: usize) -> Message {
+        Message::from_slice(&(0..=255).cycle().take(length).collect::<Vec<u8>>())
+    }
+
+    #[test]
+    fn test_xxh64() {
+        assert_eq!(
+            XXH64::new(0).hash(&Message::from_string("")).to_hex(),
+            "ef46db3751d8e999"
+        );
+        assert_eq!(
+            XXH64::new(0).hash(&Message::from_string(FOX)).to_hex(),
+            "0b242d361fda71bc"
+        );
+        assert_eq!(
+            XXH64::new(42).hash(&Message::from_string(FOX)).to_hex(),
+            "aa9f288a8baa3d3f"
+        );
+        assert_eq!(
+            XXH64::new(7).hash(&cycle(2049)).to_hex(),
+            "0d1a8f004625b625"
+        );
+    }
+
+    #[test]
+    fn test_xxh3_64() {
+        assert_eq!(
+            XXH3_64::new(0).hash(&Message::from_string("")).to_hex(),
+            "2d06800538d394c2"
+        );
+        assert_eq!(
+            XXH3_64::new(42).hash(&Message::from_string("")).to_hex(),
+            "b029411ff43d84d2"
+        );
+        assert_eq!(
+            XXH3_64::new(0).hash(&Message::from_string(FOX)).to_hex(),
+            "ce7d19a5418fb365"
+        );
+        assert_eq!(
+            XXH3_64::new(42).hash(&Message::from_string(FOX)).to_hex(),
+            "b4a3f3c36b3c7d26"
+        );
+        assert_eq!(
+            XXH3_64::new(7).hash(&cycle(200)).to_hex(),
+            "68decbfb306ebdf2"
+        );
+        assert_eq!(
+            XXH3_64::new(7).hash(&cycle(2049)).to_hex(),
+            "e03ff6728261d584"
+        );
+    }
+
+    #[test]
+    fn test_xxh3_128() {
+        assert_eq!(
+            XXH3_128::new(0).hash(&Message::from_string("")).to_hex(),
+            "99aa06d3014798d86001c324468d497f"
+        );
+        assert_eq!(
+            XXH3_128::new(42).hash(&Message::from_string("")).to_hex(),
+            "16c20acd33f7af2f3c1d09e9fe249164"
+        );
+        assert_eq!(
+            XXH3_128::new(0).hash(&Message::from_string(FOX)).to_hex(),
+            "ddd650205ca3e7fa24a1cc2e3a8a7651"
+        );
+        assert_eq!(
+            XXH3_128::new(42).hash(&Message::from_string(FOX)).to_hex(),
+            "97fdbc584d4c698472c665da6e6d93b8"
+        );
+        assert_eq!(
+            XXH3_128::new(7).hash(&cycle(200)).to_hex(),
+            "9564727b497b97f314903e5d380d7e85"
+        );
+        assert_eq!(
+            XXH3_128::new(7).hash(&cycle(2049)).to_hex(),
+            "ef6c8f5292a4b9dce03ff6728261d584"
+        );
+    }
+}
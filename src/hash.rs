@@ -1,5 +1,15 @@
 use std::{error, fmt, ops};
 
+use crate::secret;
+
+pub mod ascon;
+pub mod blake2b;
+
+pub mod groestl;
+pub mod has160;
+pub mod haval;
+pub mod jh;
+
 pub mod md2;
 pub mod md4;
 pub mod md5;
@@ -14,17 +24,36 @@ pub mod sha384;
 pub mod sha512;
 pub mod sha512_224;
 pub mod sha512_256;
+pub mod sha512_t;
+
+pub mod siphash;
+pub mod skein;
+
+pub mod sm3;
+pub mod streebog;
 
 #[derive(Debug)]
 pub enum HashError {
+    CustomizationTooLongError,
+    InvalidDigestSizeError,
     InvalidHexError,
+    InvalidKeyLengthError,
+    InvalidPassCountError,
+    InvalidStateSizeError,
     RangeOutOfBoundsError,
 }
 
 impl fmt::Display for HashError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            HashError::CustomizationTooLongError => {
+                write!(f, "Provided customization string is too long")
+            }
+            HashError::InvalidDigestSizeError => write!(f, "Invalid digest size provided"),
             HashError::InvalidHexError => write!(f, "Invalid hexadecimal string provided"),
+            HashError::InvalidKeyLengthError => write!(f, "Invalid key length provided"),
+            HashError::InvalidPassCountError => write!(f, "Invalid number of passes provided"),
+            HashError::InvalidStateSizeError => write!(f, "Invalid state size provided"),
             HashError::RangeOutOfBoundsError => write!(f, "Provided range is out of bounds"),
         }
     }
@@ -32,6 +61,27 @@ impl fmt::Display for HashError {
 
 impl error::Error for HashError {}
 
+/*
+Common interface over the fixed-output hash functions of this module,
+used by constructions such as HMAC that work with any of them. The block
+size is the number of bytes processed by one call to the compression
+function, or the rate for sponge constructions.
+*/
+pub trait HashFunction {
+    fn hash(&self, input: &Message) -> Digest;
+    fn block_size(&self) -> usize;
+}
+
+impl<H: HashFunction + ?Sized> HashFunction for &H {
+    fn hash(&self, input: &Message) -> Digest {
+        (**self).hash(input)
+    }
+
+    fn block_size(&self) -> usize {
+        (**self).block_size()
+    }
+}
+
 pub enum Endianness {
     Big,
     Little,
@@ -82,11 +132,13 @@ impl Message {
             return Err(HashError::InvalidHexError);
         }
         let mut buffer = Vec::new();
-        for i in (0..message.len()).step_by(2) {
-            let byte_str = &message[i..i + 2];
-            match u8::from_str_radix(byte_str, 16) {
-                Ok(byte) => buffer.push(byte),
-                Err(_) => return Err(HashError::InvalidHexError),
+        for pair in message.as_bytes().chunks(2) {
+            match (
+                char::from(pair[0]).to_digit(16),
+                char::from(pair[1]).to_digit(16),
+            ) {
+                (Some(high), Some(low)) => buffer.push((high << 4 | low) as u8),
+                _ => return Err(HashError::InvalidHexError),
             }
         }
         let message_size = buffer.len() * 8;
@@ -134,6 +186,12 @@ impl Message {
     }
 }
 
+impl Drop for Message {
+    fn drop(&mut self) {
+        secret::zeroize(&mut self.buffer);
+    }
+}
+
 impl fmt::Display for Message {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let hex_string: String = self.to_hex();
@@ -227,6 +285,23 @@ impl Digest {
         Ok(Self::from_u8(&digest_u8[byte_range]))
     }
 
+    /*
+    Constant-time comparisons, to be used instead of comparing hex strings
+    when checking authentication tags.
+    */
+    pub fn ct_eq(&self, other: &Digest) -> bool {
+        secret::ct_eq(&self.buffer, &other.buffer)
+    }
+
+    pub fn verify(&self, expected: &[u8]) -> bool {
+        secret::ct_eq(&self.buffer, expected)
+    }
+
+    pub fn verify_hex(&self, expected: &str) -> Result<bool, HashError> {
+        let expected: Message = Message::from_hex(expected)?;
+        Ok(self.verify(expected.to_slice()))
+    }
+
     pub fn to_hex(&self) -> String {
         self.buffer
             .iter()
@@ -247,6 +322,14 @@ impl Digest {
     }
 }
 
+impl PartialEq for Digest {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other)
+    }
+}
+
+impl Eq for Digest {}
+
 impl fmt::Display for Digest {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let hex_string: String = self.to_hex();
@@ -0,0 +1,281 @@
+use crate::hash::{Digest, HashError, HashFunction, Message};
+
+const RATE: usize = 8;
+const MAX_CUSTOMIZATION_LENGTH: usize = 256;
+
+const ROUND_CONSTANTS: [u64; 16] = [
+    0x3c, 0x2d, 0x1e, 0x0f, 0xf0, 0xe1, 0xd2, 0xc3, 0xb4, 0xa5, 0x96, 0x87, 0x78, 0x69, 0x5a, 0x4b,
+];
+
+const HASH256_IV: u64 = 0x0000080100cc0002;
+const XOF128_IV: u64 = 0x0000080000cc0003;
+const CXOF128_IV: u64 = 0x0000080000cc0004;
+
+fn substitution(s: &mut [u64; 5]) {
+    s[0] ^= s[4];
+    s[4] ^= s[3];
+    s[2] ^= s[1];
+    let t: [u64; 5] = [
+        !s[0] & s[1],
+        !s[1] & s[2],
+        !s[2] & s[3],
+        !s[3] & s[4],
+        !s[4] & s[0],
+    ];
+    for i in 0..5 {
+        s[i] ^= t[(i + 1) % 5];
+    }
+    s[1] ^= s[0];
+    s[0] ^= s[4];
+    s[3] ^= s[2];
+    s[2] = !s[2];
+}
+
+fn linear(s: &mut [u64; 5]) {
+    s[0] ^= s[0].rotate_right(19) ^ s[0].rotate_right(28);
+    s[1] ^= s[1].rotate_right(61) ^ s[1].rotate_right(39);
+    s[2] ^= s[2].rotate_right(1) ^ s[2].rotate_right(6);
+    s[3] ^= s[3].rotate_right(10) ^ s[3].rotate_right(17);
+    s[4] ^= s[4].rotate_right(7) ^ s[4].rotate_right(41);
+}
+
+/*
+The Ascon-p[rounds] permutation from NIST SP 800-232. It is shared by all
+Ascon modes, the hash functions below use the full twelve rounds.
+*/
+pub(crate) fn permutation(s: &mut [u64; 5], rounds: usize) {
+    for c in &ROUND_CONSTANTS[16 - rounds..] {
+        s[2] ^= c;
+        substitution(s);
+        linear(s);
+    }
+}
+
+/*
+Pads the input with a single 1 bit followed by zeros to a multiple of the
+rate. Since SP 800-232 reads bytes in little-endian order the 1 bit ends up
+as the byte 0x01.
+*/
+fn pad(input: &[u8]) -> Vec<u64> {
+    let mut buffer: Vec<u8> = input.to_vec();
+    buffer.push(0x01);
+    buffer.resize(buffer.len().div_ceil(RATE) * RATE, 0x00);
+    buffer
+        .chunks_exact(RATE)
+        .map(|chunk| u64::from_le_bytes(chunk.try_into().unwrap()))
+        .collect()
+}
+
+fn initialize(iv: u64) -> [u64; 5] {
+    let mut state: [u64; 5] = [iv, 0, 0, 0, 0];
+    permutation(&mut state, 12);
+    state
+}
+
+fn absorb(state: &mut [u64; 5], input: &[u8]) {
+    for block in pad(input) {
+        state[0] ^= block;
+        permutation(state, 12);
+    }
+}
+
+fn squeeze(state: &mut [u64; 5], output_length: usize) -> Digest {
+    let mut output: Vec<u8> = Vec::with_capacity(output_length + RATE);
+    loop {
+        output.extend_from_slice(&state[0].to_le_bytes());
+        if output.len() >= output_length {
+            break;
+        }
+        permutation(state, 12);
+    }
+    Digest::from_u8(&output[..output_length])
+}
+
+fn validate_output_size(d: usize) -> Result<(), HashError> {
+    if d == 0 || !d.is_multiple_of(8) {
+        return Err(HashError::InvalidDigestSizeError);
+    }
+    Ok(())
+}
+
+pub struct AsconHash256;
+
+impl AsconHash256 {
+    pub fn hash(&self, input: &Message) -> Digest {
+        let mut state: [u64; 5] = initialize(HASH256_IV);
+        absorb(&mut state, &input.buffer);
+        squeeze(&mut state, 32)
+    }
+}
+
+impl HashFunction for AsconHash256 {
+    fn hash(&self, input: &Message) -> Digest {
+        AsconHash256::hash(self, input)
+    }
+
+    fn block_size(&self) -> usize {
+        8
+    }
+}
+
+pub struct AsconXof128 {
+    d: usize,
+}
+
+impl AsconXof128 {
+    pub fn new(d: usize) -> Result<Self, HashError> {
+        validate_output_size(d)?;
+        Ok(Self { d })
+    }
+
+    pub fn hash(&self, input: &Message) -> Digest {
+        let mut state: [u64; 5] = initialize(XOF128_IV);
+        absorb(&mut state, &input.buffer);
+        squeeze(&mut state, self.d / 8)
+    }
+}
+
+impl HashFunction for AsconXof128 {
+    fn hash(&self, input: &Message) -> Digest {
+        AsconXof128::hash(self, input)
+    }
+
+    fn block_size(&self) -> usize {
+        8
+    }
+}
+
+pub struct AsconCxof128 {
+    d: usize,
+    customization: Vec<u8>,
+}
+
+impl AsconCxof128 {
+    pub fn new(d: usize, customization: &[u8]) -> Result<Self, HashError> {
+        validate_output_size(d)?;
+        if customization.len() > MAX_CUSTOMIZATION_LENGTH {
+            return Err(HashError::CustomizationTooLongError);
+        }
+        Ok(Self {
+            d,
+            customization: customization.to_vec(),
+        })
+    }
+
+    pub fn hash(&self, input: &Message) -> Digest {
+        let mut state: [u64; 5] = initialize(CXOF128_IV);
+        state[0] ^= (self.customization.len() * 8) as u64;
+        permutation(&mut state, 12);
+        absorb(&mut state, &self.customization);
+        absorb(&mut state, &input.buffer);
+        squeeze(&mut state, self.d / 8)
+    }
+}
+
+impl HashFunction for AsconCxof128 {
+    fn hash(&self, input: &Message) -> Digest {
+        AsconCxof128::hash(self, input)
+    }
+
+    fn block_size(&self) -> usize {
+        8
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn counting_message(length: usize) -> Message {
+        Message::from_slice(&(0..length as u8).collect::<Vec<u8>>())
+    }
+
+    #[test]
+    fn test_ascon_hash256() {
+        let hasher = AsconHash256;
+        let tests = [
+            (
+                0,
+                "0b3be5850f2f6b98caf29f8fdea89b64a1fa70aa249b8f839bd53baa304d92b2",
+            ),
+            (
+                1,
+                "0728621035af3ed2bca03bf6fde900f9456f5330e4b5ee23e7f6a1e70291bc80",
+            ),
+            (
+                7,
+                "3e4d273ba69b3b9c53216107e88b75cdbeedbcbf8faf0219c3928ab62b116577",
+            ),
+            (
+                8,
+                "b88e497ae8e6fb641b87ef622eb8f2fca0ed95383f7ffebe167acf1099ba764f",
+            ),
+            (
+                33,
+                "a58665a2cb9530c502096a7957a76e428af4ad044b4da5c471f9da6f7b3e5868",
+            ),
+        ];
+
+        for (length, expected) in tests {
+            assert_eq!(hasher.hash(&counting_message(length)).to_hex(), expected);
+        }
+    }
+
+    #[test]
+    fn test_ascon_xof128() {
+        let xof_256 = AsconXof128::new(256).unwrap();
+        let xof_512 = AsconXof128::new(512).unwrap();
+        let xof_40 = AsconXof128::new(40).unwrap();
+
+        assert_eq!(
+            xof_512.hash(&counting_message(0)).to_hex(),
+            "473d5e6164f58b39dfd84aacdb8ae42ec2d91fed33388ee0d960d9b3993295c6ad77855a5d3b13fe6ad9e6098988373af7d0956d05a8f1665d2c67d1a3ad10ff"
+        );
+        assert_eq!(xof_40.hash(&counting_message(0)).to_hex(), "473d5e6164");
+        assert_eq!(
+            xof_256.hash(&counting_message(1)).to_hex(),
+            "51430e0438ecdf642b393630d977625f5f337656ba58ab1e960784ac32a16e0d"
+        );
+        assert_eq!(
+            xof_256.hash(&counting_message(8)).to_hex(),
+            "8d1886f5d3ec4af8d15b44bc62b74da6ea91bc28fb82f9c34079b5ed6e38b6c9"
+        );
+    }
+
+    #[test]
+    fn test_ascon_cxof128() {
+        let tests = [
+            (
+                0,
+                0,
+                "4f50159ef70bb3dad8807e034eaebd44c4fa2cbbc8cf1f05511ab66cdcc52990",
+            ),
+            (
+                1,
+                1,
+                "fbab1c477798df70a260aa9067422a13f30781f2700bfdaefac44fc1c1e20e16",
+            ),
+            (
+                16,
+                10,
+                "0051ef5b390d571f99f726f80e05a12dfcf6e528b2593c29c30f081721502031",
+            ),
+        ];
+
+        for (message_length, customization_length, expected) in tests {
+            let customization: Vec<u8> = (0..customization_length as u8).collect();
+            let cxof = AsconCxof128::new(256, &customization).unwrap();
+            assert_eq!(
+                cxof.hash(&counting_message(message_length)).to_hex(),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_ascon_invalid_parameters() {
+        assert!(AsconXof128::new(0).is_err());
+        assert!(AsconXof128::new(255).is_err());
+        assert!(AsconCxof128::new(256, &[0; 257]).is_err());
+    }
+}
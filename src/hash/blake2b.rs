@@ -0,0 +1,241 @@
+use crate::hash::{Digest, Endianness, HashError, HashFunction, Message};
+use crate::secret;
+
+const BLOCK_SIZE: usize = 128;
+
+const IV: [u64; 8] = [
+    0x6a09e667f3bcc908,
+    0xbb67ae8584caa73b,
+    0x3c6ef372fe94f82b,
+    0xa54ff53a5f1d36f1,
+    0x510e527fade682d1,
+    0x9b05688c2b3e6c1f,
+    0x1f83d9abfb41bd6b,
+    0x5be0cd19137e2179,
+];
+
+const SIGMA: [[usize; 16]; 10] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    [14, 10, 4, 8, 9, 15, 13, 6, 1, 12, 0, 2, 11, 7, 5, 3],
+    [11, 8, 12, 0, 5, 2, 15, 13, 10, 14, 3, 6, 7, 1, 9, 4],
+    [7, 9, 3, 1, 13, 12, 11, 14, 2, 6, 5, 10, 4, 0, 15, 8],
+    [9, 0, 5, 7, 2, 4, 10, 15, 14, 1, 11, 12, 6, 8, 3, 13],
+    [2, 12, 6, 10, 0, 11, 8, 3, 4, 13, 7, 5, 15, 14, 1, 9],
+    [12, 5, 1, 15, 14, 13, 4, 10, 0, 7, 6, 3, 9, 2, 8, 11],
+    [13, 11, 7, 14, 12, 1, 3, 9, 5, 0, 15, 4, 8, 6, 2, 10],
+    [6, 15, 14, 9, 11, 3, 0, 8, 12, 2, 13, 7, 1, 4, 10, 5],
+    [10, 2, 8, 4, 7, 6, 1, 5, 15, 11, 9, 14, 3, 12, 13, 0],
+];
+
+fn g(v: &mut [u64; 16], a: usize, b: usize, c: usize, d: usize, x: u64, y: u64) {
+    v[a] = v[a].wrapping_add(v[b]).wrapping_add(x);
+    v[d] = (v[d] ^ v[a]).rotate_right(32);
+    v[c] = v[c].wrapping_add(v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(24);
+    v[a] = v[a].wrapping_add(v[b]).wrapping_add(y);
+    v[d] = (v[d] ^ v[a]).rotate_right(16);
+    v[c] = v[c].wrapping_add(v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(63);
+}
+
+fn compress(h: &mut [u64; 8], block: &[u8], counter: u128, last: bool) {
+    let m: Vec<u64> = block
+        .chunks_exact(8)
+        .map(|chunk| u64::from_le_bytes(chunk.try_into().unwrap()))
+        .collect();
+
+    let mut v: [u64; 16] = [0; 16];
+    v[..8].copy_from_slice(h);
+    v[8..].copy_from_slice(&IV);
+    v[12] ^= counter as u64;
+    v[13] ^= (counter >> 64) as u64;
+    if last {
+        v[14] = !v[14];
+    }
+
+    for round in 0..12 {
+        let s: &[usize; 16] = &SIGMA[round % 10];
+        g(&mut v, 0, 4, 8, 12, m[s[0]], m[s[1]]);
+        g(&mut v, 1, 5, 9, 13, m[s[2]], m[s[3]]);
+        g(&mut v, 2, 6, 10, 14, m[s[4]], m[s[5]]);
+        g(&mut v, 3, 7, 11, 15, m[s[6]], m[s[7]]);
+        g(&mut v, 0, 5, 10, 15, m[s[8]], m[s[9]]);
+        g(&mut v, 1, 6, 11, 12, m[s[10]], m[s[11]]);
+        g(&mut v, 2, 7, 8, 13, m[s[12]], m[s[13]]);
+        g(&mut v, 3, 4, 9, 14, m[s[14]], m[s[15]]);
+    }
+
+    for (i, h) in h.iter_mut().enumerate() {
+        *h ^= v[i] ^ v[i + 8];
+    }
+}
+
+/*
+BLAKE2b as specified in RFC 7693, with a digest size of 8 to 512 bits in
+steps of 8 and an optional key of up to 64 bytes. A keyed hash processes
+the key padded to a full block before the message.
+*/
+pub struct BLAKE2b {
+    d: usize,
+    key: Vec<u8>,
+}
+
+impl BLAKE2b {
+    pub fn new(d: usize) -> Result<Self, HashError> {
+        Self::new_with_key(d, &[])
+    }
+
+    pub fn new_with_key(d: usize, key: &[u8]) -> Result<Self, HashError> {
+        if d == 0 || d > 512 || !d.is_multiple_of(8) {
+            return Err(HashError::InvalidDigestSizeError);
+        }
+        if key.len() > 64 {
+            return Err(HashError::InvalidKeyLengthError);
+        }
+        Ok(Self {
+            d,
+            key: key.to_vec(),
+        })
+    }
+
+    pub fn hash(&self, input: &Message) -> Digest {
+        let mut h: [u64; 8] = IV;
+        h[0] ^= 0x01010000 ^ ((self.key.len() as u64) << 8) ^ (self.d / 8) as u64;
+
+        let mut data: Vec<u8> = Vec::with_capacity(BLOCK_SIZE + input.buffer.len());
+        if !self.key.is_empty() {
+            data.extend_from_slice(&self.key);
+            data.resize(BLOCK_SIZE, 0x00);
+        }
+        data.extend_from_slice(&input.buffer);
+
+        let total_length: usize = data.len();
+        let full_blocks: usize = match total_length {
+            0 => 0,
+            _ => (total_length - 1) / BLOCK_SIZE,
+        };
+        for (i, block) in data.chunks_exact(BLOCK_SIZE).take(full_blocks).enumerate() {
+            compress(&mut h, block, ((i + 1) * BLOCK_SIZE) as u128, false);
+        }
+        let mut last_block: Vec<u8> = data[full_blocks * BLOCK_SIZE..].to_vec();
+        last_block.resize(BLOCK_SIZE, 0x00);
+        compress(&mut h, &last_block, total_length as u128, true);
+
+        secret::zeroize(&mut data);
+        Digest::from_u64_range(&h, Endianness::Little, 0..self.d / 8).unwrap()
+    }
+}
+
+impl Drop for BLAKE2b {
+    fn drop(&mut self) {
+        secret::zeroize(&mut self.key);
+    }
+}
+
+impl HashFunction for BLAKE2b {
+    fn hash(&self, input: &Message) -> Digest {
+        BLAKE2b::hash(self, input)
+    }
+
+    fn block_size(&self) -> usize {
+        BLOCK_SIZE
+    }
+}
+
+pub struct BLAKE2b256;
+
+impl BLAKE2b256 {
+    pub fn hash(&self, input: &Message) -> Digest {
+        BLAKE2b::new(256).unwrap().hash(input)
+    }
+}
+
+impl HashFunction for BLAKE2b256 {
+    fn hash(&self, input: &Message) -> Digest {
+        BLAKE2b256::hash(self, input)
+    }
+
+    fn block_size(&self) -> usize {
+        BLOCK_SIZE
+    }
+}
+
+pub struct BLAKE2b512;
+
+impl BLAKE2b512 {
+    pub fn hash(&self, input: &Message) -> Digest {
+        BLAKE2b::new(512).unwrap().hash(input)
+    }
+}
+
+impl HashFunction for BLAKE2b512 {
+    fn hash(&self, input: &Message) -> Digest {
+        BLAKE2b512::hash(self, input)
+    }
+
+    fn block_size(&self) -> usize {
+        BLOCK_SIZE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blake2b_on_rfc7693_suite() {
+        let i1 = Message::from_string("abc");
+        assert_eq!(
+            BLAKE2b512.hash(&i1).to_hex(),
+            "ba80a53f981c4d0d6a2797b69f12f6e94c212f14685ac4b74b12bb6fdbffa2d17d87c5392aab792dc252d5de4533cc9518d38aa8dbf1925ab92386edd4009923"
+        );
+    }
+
+    #[test]
+    fn test_blake2b_on_block_boundaries() {
+        let tests = [
+            (0, "0e5751c026e543b2e8ab2eb06099daa1d1e5df47778f7787faab45cdf12fe3a8", "786a02f742015903c6c6fd852552d272912f4740e15847618a86e217f71f5419d25e1031afee585313896444934eb04b903a685b1448b755d56f701afe9be2ce"),
+            (127, "f2fe67ff342e21b8f45e8f2e0bcd1d9243245d50ee6c78042e9c491388791c72", "b6292669ccd38d5f01caae96ba272c76a879a45743afa0725d83b9ebb26665b731f1848c52f11972b6644f554c064fa90780dbbbf3a89d4fc31f67df3e5857ef"),
+            (128, "c3582f71ebb2be66fa5dd750f80baae97554f3b015663c8be377cfcb2488c1d1", "2319e3789c47e2daa5fe807f61bec2a1a6537fa03f19ff32e87eecbfd64b7e0e8ccff439ac333b040f19b0c4ddd11a61e24ac1fe0f10a039806c5dcc0da3d115"),
+            (129, "f7f3c46ba2564ff4c4c162da1f5b605f9f1c4aa6a20652a9f9a337c1a2f5b9c9", "f59711d44a031d5f97a9413c065d1e614c417ede998590325f49bad2fd444d3e4418be19aec4e11449ac1a57207898bc57d76a1bcf3566292c20c683a5c4648f"),
+            (256, "582f782226018ec33076bd8d1c42413530ac7e1126260ffc0f306ba3befc3f24", "93463ac058b6163eb43be3f5bb32b28541498f4e3366f1effe253ad44e1e076e41c3616046027c82a7124f8f4746668ad10b12e8e25a95ac8f3151df01cd5a93"),
+            (300, "940563f11807c8ba3192299e05cf544b82463742c8a5e80c2a5d81751cd8b0ca", "3a482b7748b0bdc43c3d00c080890c10e57a9aa5618f78b86067eb7eaae4942acd96d827accbc16958364ae5b0df6105bbd3b15445092eba1137b5f69c1070f1"),
+        ];
+
+        for (length, expected_256, expected_512) in tests {
+            let input: Vec<u8> = (0..length).map(|i| (i % 251) as u8).collect();
+            let input = Message::from_slice(&input);
+            assert_eq!(BLAKE2b256.hash(&input).to_hex(), expected_256);
+            assert_eq!(BLAKE2b512.hash(&input).to_hex(), expected_512);
+        }
+
+        let hasher = BLAKE2b::new(160).unwrap();
+        assert_eq!(
+            hasher
+                .hash(&Message::from_string(
+                    "The quick brown fox jumps over the lazy dog"
+                ))
+                .to_hex(),
+            "3c523ed102ab45a37d54f5610d5a983162fde84f"
+        );
+    }
+
+    #[test]
+    fn test_keyed_blake2b() {
+        let key: Vec<u8> = (0..64).collect();
+        let hasher = BLAKE2b::new_with_key(512, &key).unwrap();
+        assert_eq!(
+            hasher.hash(&Message::new()).to_hex(),
+            "10ebb67700b1868efb4417987acf4690ae9d972fb7a590c2f02871799aaa4786b5e996e8f0f4eb981fc214b005f42d2ff4233499391653df7aefcbc13fc51568"
+        );
+        let input: Vec<u8> = (0..255).collect();
+        assert_eq!(
+            hasher.hash(&Message::from_slice(&input)).to_hex(),
+            "142709d62e28fcccd0af97fad0f8465b971e82201dc51070faa0372aa43e92484be1c1e73ba10906d5d1853db6a4106e0a7bf9800d373d6dee2d46d62ef2a461"
+        );
+
+        assert!(BLAKE2b::new(0).is_err());
+        assert!(BLAKE2b::new(520).is_err());
+        assert!(BLAKE2b::new_with_key(512, &[0; 65]).is_err());
+    }
+}
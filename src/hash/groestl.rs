@@ -0,0 +1,278 @@
+use crate::cipher::aes::S_BOX;
+use crate::hash::{Digest, HashError, HashFunction, Message};
+
+const MIX_BYTES: [u8; 8] = [2, 2, 3, 4, 5, 3, 5, 7];
+
+const SHIFTS_P_512: [usize; 8] = [0, 1, 2, 3, 4, 5, 6, 7];
+const SHIFTS_Q_512: [usize; 8] = [1, 3, 5, 7, 0, 2, 4, 6];
+const SHIFTS_P_1024: [usize; 8] = [0, 1, 2, 3, 4, 5, 6, 11];
+const SHIFTS_Q_1024: [usize; 8] = [1, 3, 5, 11, 0, 2, 4, 6];
+
+#[derive(Clone, Copy, PartialEq)]
+enum Permutation {
+    P,
+    Q,
+}
+
+fn gf_mul(mut a: u8, mut b: u8) -> u8 {
+    let mut result: u8 = 0;
+    while b != 0 {
+        if b & 1 == 1 {
+            result ^= a;
+        }
+        a = (a << 1) ^ if a & 0x80 != 0 { 0x1b } else { 0x00 };
+        b >>= 1;
+    }
+    result
+}
+
+/*
+The state is an 8 x n matrix of bytes stored column by column, so byte
+i of column j lives at index 8 * j + i. The 512-bit state (n = 8) is used
+for digests up to 256 bits and runs 10 rounds, the 1024-bit state
+(n = 16) is used for larger digests and runs 14 rounds.
+*/
+fn permute(state: &[u8], permutation: Permutation) -> Vec<u8> {
+    let columns: usize = state.len() / 8;
+    let (rounds, shifts) = match (columns, permutation) {
+        (8, Permutation::P) => (10, SHIFTS_P_512),
+        (8, Permutation::Q) => (10, SHIFTS_Q_512),
+        (_, Permutation::P) => (14, SHIFTS_P_1024),
+        (_, Permutation::Q) => (14, SHIFTS_Q_1024),
+    };
+    let mut a: Vec<u8> = state.to_vec();
+    let mut b: Vec<u8> = vec![0; state.len()];
+
+    for r in 0..rounds {
+        for (j, column) in a.chunks_exact_mut(8).enumerate() {
+            let constant: u8 = ((j << 4) ^ r) as u8;
+            match permutation {
+                Permutation::P => column[0] ^= constant,
+                Permutation::Q => {
+                    column.iter_mut().for_each(|x| *x ^= 0xff);
+                    column[7] ^= constant;
+                }
+            }
+        }
+
+        for j in 0..columns {
+            for i in 0..8 {
+                b[8 * j + i] = S_BOX[a[8 * ((j + shifts[i]) % columns) + i] as usize];
+            }
+        }
+
+        for (a, b) in a.chunks_exact_mut(8).zip(b.chunks_exact(8)) {
+            for (i, x) in a.iter_mut().enumerate() {
+                *x = (0..8).fold(0, |acc, k| acc ^ gf_mul(MIX_BYTES[(k + 8 - i) % 8], b[k]));
+            }
+        }
+    }
+    a
+}
+
+fn pad(input: &[u8], block_size: usize) -> Vec<u8> {
+    let padding_length: usize = (block_size - (input.len() + 9) % block_size) % block_size;
+    let total_length: usize = input.len() + 1 + padding_length + 8;
+
+    let mut buffer: Vec<u8> = Vec::with_capacity(total_length);
+    buffer.extend_from_slice(input);
+    buffer.push(0x80);
+    buffer.resize(total_length - 8, 0x00);
+    buffer.extend_from_slice(&((total_length / block_size) as u64).to_be_bytes());
+    buffer
+}
+
+pub struct Groestl {
+    d: usize,
+}
+
+impl Groestl {
+    pub fn new(d: usize) -> Result<Self, HashError> {
+        if d == 0 || d > 512 || !d.is_multiple_of(8) {
+            return Err(HashError::InvalidDigestSizeError);
+        }
+        Ok(Self { d })
+    }
+
+    pub fn hash(&self, input: &Message) -> Digest {
+        let block_size: usize = if self.d <= 256 { 64 } else { 128 };
+        let mut h: Vec<u8> = vec![0; block_size];
+        h[block_size - 8..].copy_from_slice(&(self.d as u64).to_be_bytes());
+
+        for block in pad(&input.buffer, block_size).chunks_exact(block_size) {
+            let hm: Vec<u8> = h.iter().zip(block).map(|(h, m)| h ^ m).collect();
+            let p: Vec<u8> = permute(&hm, Permutation::P);
+            let q: Vec<u8> = permute(block, Permutation::Q);
+            for ((h, p), q) in h.iter_mut().zip(&p).zip(&q) {
+                *h ^= p ^ q;
+            }
+        }
+
+        let p: Vec<u8> = permute(&h, Permutation::P);
+        let output: Vec<u8> = h.iter().zip(&p).map(|(h, p)| h ^ p).collect();
+        Digest::from_u8(&output[block_size - self.d / 8..])
+    }
+}
+
+impl HashFunction for Groestl {
+    fn hash(&self, input: &Message) -> Digest {
+        Groestl::hash(self, input)
+    }
+
+    fn block_size(&self) -> usize {
+        if self.d <= 256 {
+            64
+        } else {
+            128
+        }
+    }
+}
+
+pub struct Groestl224;
+
+impl Groestl224 {
+    pub fn hash(&self, input: &Message) -> Digest {
+        Groestl::new(224).unwrap().hash(input)
+    }
+}
+
+impl HashFunction for Groestl224 {
+    fn hash(&self, input: &Message) -> Digest {
+        Groestl224::hash(self, input)
+    }
+
+    fn block_size(&self) -> usize {
+        64
+    }
+}
+
+pub struct Groestl256;
+
+impl Groestl256 {
+    pub fn hash(&self, input: &Message) -> Digest {
+        Groestl::new(256).unwrap().hash(input)
+    }
+}
+
+impl HashFunction for Groestl256 {
+    fn hash(&self, input: &Message) -> Digest {
+        Groestl256::hash(self, input)
+    }
+
+    fn block_size(&self) -> usize {
+        64
+    }
+}
+
+pub struct Groestl384;
+
+impl Groestl384 {
+    pub fn hash(&self, input: &Message) -> Digest {
+        Groestl::new(384).unwrap().hash(input)
+    }
+}
+
+impl HashFunction for Groestl384 {
+    fn hash(&self, input: &Message) -> Digest {
+        Groestl384::hash(self, input)
+    }
+
+    fn block_size(&self) -> usize {
+        128
+    }
+}
+
+pub struct Groestl512;
+
+impl Groestl512 {
+    pub fn hash(&self, input: &Message) -> Digest {
+        Groestl::new(512).unwrap().hash(input)
+    }
+}
+
+impl HashFunction for Groestl512 {
+    fn hash(&self, input: &Message) -> Digest {
+        Groestl512::hash(self, input)
+    }
+
+    fn block_size(&self) -> usize {
+        128
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_groestl_on_empty_message() {
+        let input = Message::from_string("");
+
+        assert_eq!(
+            Groestl224.hash(&input).to_hex(),
+            "f2e180fb5947be964cd584e22e496242c6a329c577fc4ce8c36d34c3"
+        );
+        assert_eq!(
+            Groestl256.hash(&input).to_hex(),
+            "1a52d11d550039be16107f9c58db9ebcc417f16f736adb2502567119f0083467"
+        );
+        assert_eq!(
+            Groestl384.hash(&input).to_hex(),
+            "ac353c1095ace21439251007862d6c62f829ddbe6de4f78e68d310a9205a736d8b11d99bffe448f57a1cfa2934f044a5"
+        );
+        assert_eq!(
+            Groestl512.hash(&input).to_hex(),
+            "6d3ad29d279110eef3adbd66de2a0345a77baede1557f5d099fce0c03d6dc2ba8e6d4a6633dfbd66053c20faa87d1a11f39a7fbe4a6c2f009801370308fc4ad8"
+        );
+    }
+
+    #[test]
+    fn test_groestl_on_quick_brown_fox() {
+        let input = Message::from_string("The quick brown fox jumps over the lazy dog");
+
+        assert_eq!(
+            Groestl224.hash(&input).to_hex(),
+            "8ce3ce0f7092cada755be8f614fd6d5e5738ff1f6cd5dabe42404c46"
+        );
+        assert_eq!(
+            Groestl256.hash(&input).to_hex(),
+            "8c7ad62eb26a21297bc39c2d7293b4bd4d3399fa8afab29e970471739e28b301"
+        );
+        assert_eq!(
+            Groestl384.hash(&input).to_hex(),
+            "9330aeb62a1fc0a464dd70ac27b57075e00ae5d627f9bd6ff72952b3857aba2cfbcc4345af9a04fcc13eb346829e4088"
+        );
+        assert_eq!(
+            Groestl512.hash(&input).to_hex(),
+            "badc1f70ccd69e0cf3760c3f93884289da84ec13c70b3d12a53a7a8a4a513f99715d46288f55e1dbf926e6d084a0538e4eebfc91cf2b21452921ccde9131718d"
+        );
+    }
+
+    #[test]
+    fn test_groestl_multi_block_message() {
+        let buffer: Vec<u8> = (0..=255).cycle().take(512).collect();
+        let input = Message::from_slice(&buffer);
+
+        assert_eq!(
+            Groestl256.hash(&input).to_hex(),
+            "1f15c727f9d1a2aab816fd36dcc12dc30b6a7dedd6e475639b2ca935275134ca"
+        );
+        assert_eq!(
+            Groestl512.hash(&input).to_hex(),
+            "b5ce86bf903e6ef59466d9501ef47a1e79a37585a8cb991f018a3c1388fc4e541495a72cb0f91c949f07bbbbaae353e109464b183a35ecfed399d11fc03381b2"
+        );
+    }
+
+    #[test]
+    fn test_groestl_custom_digest_size() {
+        let input = Message::from_string("abc");
+
+        assert_eq!(
+            Groestl::new(160).unwrap().hash(&input).to_hex(),
+            "37ff17d9551bedaa9e4dad6e3e06f743a29e1844"
+        );
+        assert!(Groestl::new(0).is_err());
+        assert!(Groestl::new(100).is_err());
+        assert!(Groestl::new(520).is_err());
+    }
+}
@@ -0,0 +1,155 @@
+use crate::hash::{Digest, Endianness, HashFunction, Message};
+
+const IV: [u32; 5] = [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476, 0xc3d2e1f0];
+
+const K: [u32; 4] = [0x00000000, 0x5a827999, 0x6ed9eba1, 0x8f1bbcdc];
+
+/*
+Every round extends the 16 message words with four extra words, each
+one the XOR of four message words chosen by the tables below.
+*/
+const EXPANSION: [[[usize; 4]; 4]; 4] = [
+    [[0, 1, 2, 3], [4, 5, 6, 7], [8, 9, 10, 11], [12, 13, 14, 15]],
+    [[3, 6, 9, 12], [15, 2, 5, 8], [11, 14, 1, 4], [7, 10, 13, 0]],
+    [[12, 5, 14, 7], [0, 9, 2, 11], [4, 13, 6, 15], [8, 1, 10, 3]],
+    [[7, 2, 13, 8], [3, 14, 9, 4], [15, 10, 5, 0], [11, 6, 1, 12]],
+];
+
+const WORD_ORDER: [[usize; 20]; 4] = [
+    [
+        18, 0, 1, 2, 3, 19, 4, 5, 6, 7, 16, 8, 9, 10, 11, 17, 12, 13, 14, 15,
+    ],
+    [
+        18, 3, 6, 9, 12, 19, 15, 2, 5, 8, 16, 11, 14, 1, 4, 17, 7, 10, 13, 0,
+    ],
+    [
+        18, 12, 5, 14, 7, 19, 0, 9, 2, 11, 16, 4, 13, 6, 15, 17, 8, 1, 10, 3,
+    ],
+    [
+        18, 7, 2, 13, 8, 19, 3, 14, 9, 4, 16, 15, 10, 5, 0, 17, 11, 6, 1, 12,
+    ],
+];
+
+const S1: [u32; 20] = [
+    5, 11, 7, 15, 6, 13, 8, 14, 7, 12, 9, 11, 8, 15, 6, 12, 9, 14, 5, 13,
+];
+
+const S2: [u32; 4] = [10, 17, 25, 30];
+
+fn pad(input: &[u8]) -> Vec<u32> {
+    let input_length: u64 = input.len() as u64;
+    let input_length_in_bits: u64 = input_length * 8;
+    let length_le_bytes: [u8; 8] = input_length_in_bits.to_le_bytes();
+
+    let input_length_mod_64: u64 = input_length % 64;
+    let padding_length: u64 = match input_length_mod_64 {
+        56 => 64,
+        _ => (56 + 64 - input_length_mod_64) % 64,
+    };
+
+    let total_length = (input_length + padding_length + 8) as usize;
+    let mut buffer: Vec<u8> = Vec::with_capacity(total_length);
+
+    buffer.extend_from_slice(input);
+    buffer.push(0x80);
+    buffer.resize((input_length + padding_length) as usize, 0x00);
+    buffer.extend_from_slice(&length_le_bytes);
+
+    buffer
+        .chunks_exact(4)
+        .map(|chunk| u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+        .collect()
+}
+
+fn f(round: usize, b: u32, c: u32, d: u32) -> u32 {
+    match round {
+        0 => (b & c) | (!b & d),
+        2 => c ^ (b | !d),
+        _ => b ^ c ^ d,
+    }
+}
+
+pub struct HAS160;
+
+impl HAS160 {
+    pub fn hash(&self, input: &Message) -> Digest {
+        let input: Vec<u32> = pad(&input.buffer);
+        let mut h: [u32; 5] = IV;
+
+        for block in input.chunks_exact(16) {
+            let mut x: [u32; 20] = [0; 20];
+            x[..16].copy_from_slice(block);
+            let [mut a, mut b, mut c, mut d, mut e] = h;
+
+            for round in 0..4 {
+                for (k, indices) in EXPANSION[round].iter().enumerate() {
+                    x[16 + k] = indices.iter().fold(0, |acc, &i| acc ^ x[i]);
+                }
+                for j in 0..20 {
+                    let t: u32 = a
+                        .rotate_left(S1[j])
+                        .wrapping_add(f(round, b, c, d))
+                        .wrapping_add(e)
+                        .wrapping_add(x[WORD_ORDER[round][j]])
+                        .wrapping_add(K[round]);
+                    e = d;
+                    d = c;
+                    c = b.rotate_left(S2[round]);
+                    b = a;
+                    a = t;
+                }
+            }
+
+            for (h, v) in h.iter_mut().zip([a, b, c, d, e]) {
+                *h = h.wrapping_add(v);
+            }
+        }
+        Digest::from_u32(&h, Endianness::Little)
+    }
+}
+
+impl HashFunction for HAS160 {
+    fn hash(&self, input: &Message) -> Digest {
+        HAS160::hash(self, input)
+    }
+
+    fn block_size(&self) -> usize {
+        64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_has160_on_tta_suite() {
+        let tests = [
+            ("", "307964ef34151d37c8047adec7ab50f4ff89762d"),
+            ("a", "4872bcbc4cd0f0a9dc7c2f7045e5b43b6c830db8"),
+            ("abc", "975e810488cf2a3d49838478124afce4b1c78804"),
+            ("message digest", "2338dbc8638d31225f73086246ba529f96710bc6"),
+            (
+                "The quick brown fox jumps over the lazy dog",
+                "abe2b8c711f9e8579aa8eb40757a27b4ef14a7ea",
+            ),
+        ];
+
+        for (message, expected) in tests {
+            assert_eq!(
+                HAS160.hash(&Message::from_string(message)).to_hex(),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_has160_multi_block_message() {
+        let input = Message::from_slice(&(0..=255).collect::<Vec<u8>>());
+
+        assert_eq!(
+            HAS160.hash(&input).to_hex(),
+            "63294bd1a6f9d553651f388e85d953658725ce46"
+        );
+    }
+}
@@ -0,0 +1,352 @@
+use crate::hash::{Digest, Endianness, HashError, HashFunction, Message};
+
+const VERSION: u8 = 1;
+
+const IV: [u32; 8] = [
+    0x243f6a88, 0x85a308d3, 0x13198a2e, 0x03707344, 0xa4093822, 0x299f31d0, 0x082efa98, 0xec4e6c89,
+];
+
+const K: [u32; 128] = [
+    0x452821e6, 0x38d01377, 0xbe5466cf, 0x34e90c6c, 0xc0ac29b7, 0xc97c50dd, 0x3f84d5b5, 0xb5470917,
+    0x9216d5d9, 0x8979fb1b, 0xd1310ba6, 0x98dfb5ac, 0x2ffd72db, 0xd01adfb7, 0xb8e1afed, 0x6a267e96,
+    0xba7c9045, 0xf12c7f99, 0x24a19947, 0xb3916cf7, 0x0801f2e2, 0x858efc16, 0x636920d8, 0x71574e69,
+    0xa458fea3, 0xf4933d7e, 0x0d95748f, 0x728eb658, 0x718bcd58, 0x82154aee, 0x7b54a41d, 0xc25a59b5,
+    0x9c30d539, 0x2af26013, 0xc5d1b023, 0x286085f0, 0xca417918, 0xb8db38ef, 0x8e79dcb0, 0x603a180e,
+    0x6c9e0e8b, 0xb01e8a3e, 0xd71577c1, 0xbd314b27, 0x78af2fda, 0x55605c60, 0xe65525f3, 0xaa55ab94,
+    0x57489862, 0x63e81440, 0x55ca396a, 0x2aab10b6, 0xb4cc5c34, 0x1141e8ce, 0xa15486af, 0x7c72e993,
+    0xb3ee1411, 0x636fbc2a, 0x2ba9c55d, 0x741831f6, 0xce5c3e16, 0x9b87931e, 0xafd6ba33, 0x6c24cf5c,
+    0x7a325381, 0x28958677, 0x3b8f4898, 0x6b4bb9af, 0xc4bfe81b, 0x66282193, 0x61d809cc, 0xfb21a991,
+    0x487cac60, 0x5dec8032, 0xef845d5d, 0xe98575b1, 0xdc262302, 0xeb651b88, 0x23893e81, 0xd396acc5,
+    0x0f6d6ff3, 0x83f44239, 0x2e0b4482, 0xa4842004, 0x69c8f04a, 0x9e1f9b5e, 0x21c66842, 0xf6e96c9a,
+    0x670c9c61, 0xabd388f0, 0x6a51a0d2, 0xd8542f68, 0x960fa728, 0xab5133a3, 0x6eef0b6c, 0x137a3be4,
+    0xba3bf050, 0x7efb2a98, 0xa1f1651d, 0x39af0176, 0x66ca593e, 0x82430e88, 0x8cee8619, 0x456f9fb4,
+    0x7d84a5c3, 0x3b8b5ebe, 0xe06f75d8, 0x85c12073, 0x401a449f, 0x56c16aa6, 0x4ed3aa62, 0x363f7706,
+    0x1bfedf72, 0x429b023d, 0x37d0d724, 0xd00a1248, 0xdb0fead3, 0x49f1c09b, 0x075372c9, 0x80991b7b,
+    0x25d479d8, 0xf6e8def7, 0xe3fe501a, 0xb6794c3b, 0x976ce0bd, 0x04c006ba, 0xc1a94fb6, 0x409f60c4,
+];
+
+const WORD_ORDER: [[usize; 32]; 5] = [
+    [
+        0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24,
+        25, 26, 27, 28, 29, 30, 31,
+    ],
+    [
+        5, 14, 26, 18, 11, 28, 7, 16, 0, 23, 20, 22, 1, 10, 4, 8, 30, 3, 21, 9, 17, 24, 29, 6, 19,
+        12, 15, 13, 2, 25, 31, 27,
+    ],
+    [
+        19, 9, 4, 20, 28, 17, 8, 22, 29, 14, 25, 12, 24, 30, 16, 26, 31, 15, 7, 3, 1, 0, 18, 27,
+        13, 6, 21, 10, 23, 11, 5, 2,
+    ],
+    [
+        24, 4, 0, 14, 2, 7, 28, 23, 26, 6, 30, 20, 18, 25, 19, 3, 22, 11, 31, 21, 8, 27, 12, 9, 1,
+        29, 5, 15, 17, 10, 16, 13,
+    ],
+    [
+        27, 3, 21, 26, 17, 11, 20, 29, 19, 0, 12, 7, 13, 8, 31, 10, 5, 9, 14, 30, 18, 6, 28, 24, 2,
+        23, 16, 22, 4, 1, 25, 15,
+    ],
+];
+
+/*
+The permutations phi applied to the arguments of the boolean function of
+every pass, which depend on the total number of passes. Entry k names the
+register passed as the k-th argument, starting from x6.
+*/
+const PHI_3: [[usize; 7]; 3] = [
+    [1, 0, 3, 5, 6, 2, 4],
+    [4, 2, 1, 0, 5, 3, 6],
+    [6, 1, 2, 3, 4, 5, 0],
+];
+const PHI_4: [[usize; 7]; 4] = [
+    [2, 6, 1, 4, 5, 3, 0],
+    [3, 5, 2, 0, 1, 6, 4],
+    [1, 4, 3, 6, 0, 2, 5],
+    [6, 4, 0, 5, 2, 1, 3],
+];
+const PHI_5: [[usize; 7]; 5] = [
+    [3, 4, 1, 0, 5, 2, 6],
+    [6, 2, 1, 0, 3, 4, 5],
+    [2, 6, 0, 4, 3, 1, 5],
+    [1, 5, 3, 2, 0, 4, 6],
+    [2, 5, 0, 6, 4, 3, 1],
+];
+
+fn f1(x6: u32, x5: u32, x4: u32, x3: u32, x2: u32, x1: u32, x0: u32) -> u32 {
+    (x1 & x4) ^ (x2 & x5) ^ (x3 & x6) ^ (x0 & x1) ^ x0
+}
+
+fn f2(x6: u32, x5: u32, x4: u32, x3: u32, x2: u32, x1: u32, x0: u32) -> u32 {
+    (x1 & x2 & x3)
+        ^ (x2 & x4 & x5)
+        ^ (x1 & x2)
+        ^ (x1 & x4)
+        ^ (x2 & x6)
+        ^ (x3 & x5)
+        ^ (x4 & x5)
+        ^ (x0 & x2)
+        ^ x0
+}
+
+fn f3(x6: u32, x5: u32, x4: u32, x3: u32, x2: u32, x1: u32, x0: u32) -> u32 {
+    (x1 & x2 & x3) ^ (x1 & x4) ^ (x2 & x5) ^ (x3 & x6) ^ (x0 & x3) ^ x0
+}
+
+fn f4(x6: u32, x5: u32, x4: u32, x3: u32, x2: u32, x1: u32, x0: u32) -> u32 {
+    (x1 & x2 & x3)
+        ^ (x2 & x4 & x5)
+        ^ (x3 & x4 & x6)
+        ^ (x1 & x4)
+        ^ (x2 & x6)
+        ^ (x3 & x4)
+        ^ (x3 & x5)
+        ^ (x3 & x6)
+        ^ (x4 & x5)
+        ^ (x4 & x6)
+        ^ (x0 & x4)
+        ^ x0
+}
+
+fn f5(x6: u32, x5: u32, x4: u32, x3: u32, x2: u32, x1: u32, x0: u32) -> u32 {
+    (x1 & x4) ^ (x2 & x5) ^ (x3 & x6) ^ (x0 & x1 & x2 & x3) ^ (x0 & x5) ^ x0
+}
+
+type BooleanFunction = fn(u32, u32, u32, u32, u32, u32, u32) -> u32;
+
+const BOOLEAN_FUNCTIONS: [BooleanFunction; 5] = [f1, f2, f3, f4, f5];
+
+/*
+Folds the 256-bit chaining value into the requested digest size. The
+unused words are split into pieces and added to the words kept.
+*/
+fn fold(state: &mut [u32; 8], d: usize) {
+    let [_, _, _, _, d4, d5, d6, d7] = *state;
+    match d {
+        128 => {
+            state[0] = state[0].wrapping_add(
+                ((d7 & 0x000000ff) | (d6 & 0xff000000) | (d5 & 0x00ff0000) | (d4 & 0x0000ff00))
+                    .rotate_right(8),
+            );
+            state[1] = state[1].wrapping_add(
+                ((d7 & 0x0000ff00) | (d6 & 0x000000ff) | (d5 & 0xff000000) | (d4 & 0x00ff0000))
+                    .rotate_right(16),
+            );
+            state[2] = state[2].wrapping_add(
+                ((d7 & 0x00ff0000) | (d6 & 0x0000ff00) | (d5 & 0x000000ff) | (d4 & 0xff000000))
+                    .rotate_right(24),
+            );
+            state[3] = state[3].wrapping_add(
+                (d7 & 0xff000000) | (d6 & 0x00ff0000) | (d5 & 0x0000ff00) | (d4 & 0x000000ff),
+            );
+        }
+        160 => {
+            state[0] = state[0].wrapping_add(
+                ((d7 & 0x3f) | (d6 & (0x7f << 25)) | (d5 & (0x3f << 19))).rotate_right(19),
+            );
+            state[1] = state[1].wrapping_add(
+                ((d7 & (0x3f << 6)) | (d6 & 0x3f) | (d5 & (0x7f << 25))).rotate_right(25),
+            );
+            state[2] =
+                state[2].wrapping_add((d7 & (0x7f << 12)) | (d6 & (0x3f << 6)) | (d5 & 0x3f));
+            state[3] = state[3].wrapping_add(
+                ((d7 & (0x3f << 19)) | (d6 & (0x7f << 12)) | (d5 & (0x3f << 6))) >> 6,
+            );
+            state[4] = state[4].wrapping_add(
+                ((d7 & (0x7f << 25)) | (d6 & (0x3f << 19)) | (d5 & (0x7f << 12))) >> 12,
+            );
+        }
+        192 => {
+            state[0] = state[0].wrapping_add(((d7 & 0x1f) | (d6 & (0x3f << 26))).rotate_right(26));
+            state[1] = state[1].wrapping_add((d7 & (0x1f << 5)) | (d6 & 0x1f));
+            state[2] = state[2].wrapping_add(((d7 & (0x3f << 10)) | (d6 & (0x1f << 5))) >> 5);
+            state[3] = state[3].wrapping_add(((d7 & (0x1f << 16)) | (d6 & (0x3f << 10))) >> 10);
+            state[4] = state[4].wrapping_add(((d7 & (0x1f << 21)) | (d6 & (0x1f << 16))) >> 16);
+            state[5] = state[5].wrapping_add(((d7 & (0x3f << 26)) | (d6 & (0x1f << 21))) >> 21);
+        }
+        224 => {
+            state[0] = state[0].wrapping_add((d7 >> 27) & 0x1f);
+            state[1] = state[1].wrapping_add((d7 >> 22) & 0x1f);
+            state[2] = state[2].wrapping_add((d7 >> 18) & 0x0f);
+            state[3] = state[3].wrapping_add((d7 >> 13) & 0x1f);
+            state[4] = state[4].wrapping_add((d7 >> 9) & 0x0f);
+            state[5] = state[5].wrapping_add((d7 >> 4) & 0x1f);
+            state[6] = state[6].wrapping_add(d7 & 0x0f);
+        }
+        _ => {}
+    }
+}
+
+pub struct HAVAL {
+    passes: usize,
+    d: usize,
+}
+
+impl HAVAL {
+    pub fn new(passes: usize, d: usize) -> Result<Self, HashError> {
+        if !(3..=5).contains(&passes) {
+            return Err(HashError::InvalidPassCountError);
+        }
+        if !matches!(d, 128 | 160 | 192 | 224 | 256) {
+            return Err(HashError::InvalidDigestSizeError);
+        }
+        Ok(Self { passes, d })
+    }
+
+    /*
+    HAVAL pads with a single 1 bit, then appends the version, the number
+    of passes and the digest size packed into two bytes, followed by the
+    64-bit little-endian message length in bits.
+    */
+    fn pad(&self, input: &[u8]) -> Vec<u32> {
+        let input_length_in_bits: u64 = input.len() as u64 * 8;
+        let padding_length: usize = (128 + 118 - (input.len() + 1) % 128) % 128;
+
+        let mut buffer: Vec<u8> = Vec::with_capacity(input.len() + 1 + padding_length + 10);
+        buffer.extend_from_slice(input);
+        buffer.push(0x01);
+        buffer.resize(input.len() + 1 + padding_length, 0x00);
+        buffer.push((((self.d & 0x03) << 6) | (self.passes << 3)) as u8 | VERSION);
+        buffer.push((self.d >> 2) as u8);
+        buffer.extend_from_slice(&input_length_in_bits.to_le_bytes());
+
+        buffer
+            .chunks_exact(4)
+            .map(|chunk| u32::from_le_bytes(chunk.try_into().unwrap()))
+            .collect()
+    }
+
+    fn compress(&self, state: &mut [u32; 8], block: &[u32]) {
+        let phi: &[[usize; 7]] = match self.passes {
+            3 => &PHI_3,
+            4 => &PHI_4,
+            _ => &PHI_5,
+        };
+        let mut t: [u32; 8] = *state;
+
+        for (pass, phi) in phi.iter().enumerate() {
+            let f: BooleanFunction = BOOLEAN_FUNCTIONS[pass];
+            for i in 0..32 {
+                let x: [u32; 8] = std::array::from_fn(|k| t[(k + 8 - i % 8) % 8]);
+                let temp: u32 = f(
+                    x[phi[0]], x[phi[1]], x[phi[2]], x[phi[3]], x[phi[4]], x[phi[5]], x[phi[6]],
+                );
+                let constant: u32 = if pass == 0 { 0 } else { K[32 * (pass - 1) + i] };
+                t[(15 - i % 8) % 8] = temp
+                    .rotate_right(7)
+                    .wrapping_add(x[7].rotate_right(11))
+                    .wrapping_add(block[WORD_ORDER[pass][i]])
+                    .wrapping_add(constant);
+            }
+        }
+
+        for (s, t) in state.iter_mut().zip(&t) {
+            *s = s.wrapping_add(*t);
+        }
+    }
+
+    pub fn hash(&self, input: &Message) -> Digest {
+        let mut state: [u32; 8] = IV;
+        for block in self.pad(&input.buffer).chunks_exact(32) {
+            self.compress(&mut state, block);
+        }
+        fold(&mut state, self.d);
+        Digest::from_u32(&state[..self.d / 32], Endianness::Little)
+    }
+}
+
+impl HashFunction for HAVAL {
+    fn hash(&self, input: &Message) -> Digest {
+        HAVAL::hash(self, input)
+    }
+
+    fn block_size(&self) -> usize {
+        128
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_haval_on_empty_message() {
+        let input = Message::from_string("");
+        let tests = [
+            (3, 128, "c68f39913f901f3ddf44c707357a7d70"),
+            (3, 160, "d353c3ae22a25401d257643836d7231a9a95f953"),
+            (3, 192, "e9c48d7903eaf2a91c5b350151efcb175c0fc82de2289a4e"),
+            (
+                3,
+                224,
+                "c5aae9d47bffcaaf84a8c6e7ccacd60a0dd1932be7b1a192b9214b6d",
+            ),
+            (4, 128, "ee6bbf4d6a46a679b3a856c88538bb98"),
+            (
+                4,
+                256,
+                "c92b2e23091e80e375dadce26982482d197b1a2521be82da819f8ca2c579b99b",
+            ),
+            (5, 128, "184b8482a0c050dca54b59c7f05bf5dd"),
+            (
+                5,
+                256,
+                "be417bb4dd5cfb76c7126f4f8eeb1553a449039307b1a3cd451dbfdc0fbbe330",
+            ),
+        ];
+
+        for (passes, d, expected) in tests {
+            assert_eq!(
+                HAVAL::new(passes, d).unwrap().hash(&input).to_hex(),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_haval_on_quick_brown_fox() {
+        let input = Message::from_string("The quick brown fox jumps over the lazy dog");
+        let tests = [
+            (3, 128, "713502673d67e5fa557629a71d331945"),
+            (4, 192, "228ee09bc7e36151c6f285f558e6aede66ad38c8341592b9"),
+            (
+                4,
+                224,
+                "dddd6689885f6db4ad91e35a35e1f4498446510df798d4fd54b8654f",
+            ),
+            (5, 160, "ecce9fa8a428866304ff082af2f9062637d36b23"),
+            (
+                5,
+                256,
+                "b89c551cdfe2e06dbd4cea2be1bc7d557416c58ebb4d07cbc94e49f710c55be4",
+            ),
+        ];
+
+        for (passes, d, expected) in tests {
+            assert_eq!(
+                HAVAL::new(passes, d).unwrap().hash(&input).to_hex(),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_haval_multi_block_message() {
+        let input = Message::from_slice(&(0..=255).collect::<Vec<u8>>());
+
+        assert_eq!(
+            HAVAL::new(3, 256).unwrap().hash(&input).to_hex(),
+            "33256f57dc8059bb8ad2bb51bbbe132ca22713cbbd5852d4e793efb63146375c"
+        );
+        assert_eq!(
+            HAVAL::new(5, 224).unwrap().hash(&input).to_hex(),
+            "0af588eea037770e9ff0aa0618daab09f80db1d26b552f980b3bff31"
+        );
+    }
+
+    #[test]
+    fn test_haval_invalid_parameters() {
+        assert!(HAVAL::new(2, 256).is_err());
+        assert!(HAVAL::new(6, 256).is_err());
+        assert!(HAVAL::new(3, 512).is_err());
+    }
+}
@@ -0,0 +1,313 @@
+use crate::hash::{Digest, HashError, HashFunction, Message};
+
+const S_BOXES: [[u8; 16]; 2] = [
+    [9, 0, 4, 11, 13, 12, 3, 15, 1, 10, 2, 6, 7, 5, 8, 14],
+    [3, 12, 6, 13, 5, 7, 1, 9, 15, 2, 0, 4, 11, 10, 14, 8],
+];
+
+const C0: [u8; 32] = [
+    0x6a, 0x09, 0xe6, 0x67, 0xf3, 0xbc, 0xc9, 0x08, 0xb2, 0xfb, 0x13, 0x66, 0xea, 0x95, 0x7d, 0x3e,
+    0x3a, 0xde, 0xc1, 0x75, 0x12, 0x77, 0x50, 0x99, 0xda, 0x2f, 0x59, 0x0b, 0x06, 0x67, 0x32, 0x2a,
+];
+
+const ROUNDS: usize = 42;
+
+fn bit(bytes: &[u8], i: usize) -> u8 {
+    (bytes[i / 8] >> (7 - i % 8)) & 1
+}
+
+/* The MDS code over GF(2^4) applied to a pair of nibbles. */
+fn l(a: u8, b: u8) -> (u8, u8) {
+    let b = b ^ (((a << 1) ^ (a >> 3) ^ ((a >> 2) & 2)) & 0x0f);
+    let a = a ^ (((b << 1) ^ (b >> 3) ^ ((b >> 2) & 2)) & 0x0f);
+    (a, b)
+}
+
+/*
+Applies the linear transformation to every pair of nibbles followed by
+the permutation P_d, which is the composition of pi, P' and phi.
+*/
+fn linear_permute(nibbles: &mut [u8]) -> Vec<u8> {
+    let half: usize = nibbles.len() / 2;
+
+    for pair in nibbles.chunks_exact_mut(2) {
+        (pair[0], pair[1]) = l(pair[0], pair[1]);
+    }
+    for quad in nibbles.chunks_exact_mut(4) {
+        quad.swap(2, 3);
+    }
+
+    let mut output: Vec<u8> = vec![0; nibbles.len()];
+    for (i, pair) in nibbles.chunks_exact(2).enumerate() {
+        output[i] = pair[0];
+        output[i + half] = pair[1];
+    }
+    for pair in output[half..].chunks_exact_mut(2) {
+        pair.swap(0, 1);
+    }
+    output
+}
+
+fn next_round_constant(rc: &[u8; 32]) -> [u8; 32] {
+    let mut nibbles: Vec<u8> = rc
+        .iter()
+        .flat_map(|byte| {
+            [
+                S_BOXES[0][(byte >> 4) as usize],
+                S_BOXES[0][(byte & 0x0f) as usize],
+            ]
+        })
+        .collect();
+    let nibbles: Vec<u8> = linear_permute(&mut nibbles);
+
+    let mut output: [u8; 32] = [0; 32];
+    for (byte, pair) in output.iter_mut().zip(nibbles.chunks_exact(2)) {
+        *byte = (pair[0] << 4) | pair[1];
+    }
+    output
+}
+
+/*
+The bijective function E8. The 1024-bit state is first grouped into 256
+nibbles, where nibble i is made of the bits i, i + 256, i + 512 and
+i + 768 of the state, then goes through 42 rounds and is finally
+degrouped back into bytes.
+*/
+fn e8(h: &[u8]) -> Vec<u8> {
+    let mut grouped: Vec<u8> = vec![0; 256];
+    for (i, nibble) in grouped.iter_mut().enumerate() {
+        *nibble =
+            (bit(h, i) << 3) | (bit(h, i + 256) << 2) | (bit(h, i + 512) << 1) | bit(h, i + 768);
+    }
+    let mut a: Vec<u8> = vec![0; 256];
+    for i in 0..128 {
+        a[2 * i] = grouped[i];
+        a[2 * i + 1] = grouped[i + 128];
+    }
+
+    let mut rc: [u8; 32] = C0;
+    for _ in 0..ROUNDS {
+        for (i, nibble) in a.iter_mut().enumerate() {
+            *nibble = S_BOXES[bit(&rc, i) as usize][*nibble as usize];
+        }
+        a = linear_permute(&mut a);
+        rc = next_round_constant(&rc);
+    }
+
+    for i in 0..128 {
+        grouped[i] = a[2 * i];
+        grouped[i + 128] = a[2 * i + 1];
+    }
+    let mut output: Vec<u8> = vec![0; 128];
+    for (i, nibble) in grouped.iter().enumerate() {
+        for k in 0..4 {
+            output[(i + 256 * k) / 8] |= ((nibble >> (3 - k)) & 1) << (7 - i % 8);
+        }
+    }
+    output
+}
+
+fn f8(h: &mut Vec<u8>, block: &[u8]) {
+    for (h, m) in h[..64].iter_mut().zip(block) {
+        *h ^= m;
+    }
+    *h = e8(h);
+    for (h, m) in h[64..].iter_mut().zip(block) {
+        *h ^= m;
+    }
+}
+
+fn pad(input: &[u8]) -> Vec<u8> {
+    let input_length_in_bits: u128 = input.len() as u128 * 8;
+    let padding_length: usize = 64 + (64 - input.len() % 64) % 64;
+
+    let mut buffer: Vec<u8> = Vec::with_capacity(input.len() + padding_length);
+    buffer.extend_from_slice(input);
+    buffer.push(0x80);
+    buffer.resize(input.len() + padding_length - 16, 0x00);
+    buffer.extend_from_slice(&input_length_in_bits.to_be_bytes());
+    buffer
+}
+
+pub struct JH {
+    d: usize,
+}
+
+impl JH {
+    pub fn new(d: usize) -> Result<Self, HashError> {
+        if d == 0 || d > 512 || !d.is_multiple_of(8) {
+            return Err(HashError::InvalidDigestSizeError);
+        }
+        Ok(Self { d })
+    }
+
+    pub fn hash(&self, input: &Message) -> Digest {
+        let mut h: Vec<u8> = vec![0; 128];
+        h[..2].copy_from_slice(&(self.d as u16).to_be_bytes());
+        f8(&mut h, &[0; 64]);
+
+        for block in pad(&input.buffer).chunks_exact(64) {
+            f8(&mut h, block);
+        }
+        Digest::from_u8(&h[128 - self.d / 8..])
+    }
+}
+
+impl HashFunction for JH {
+    fn hash(&self, input: &Message) -> Digest {
+        JH::hash(self, input)
+    }
+
+    fn block_size(&self) -> usize {
+        64
+    }
+}
+
+pub struct JH224;
+
+impl JH224 {
+    pub fn hash(&self, input: &Message) -> Digest {
+        JH::new(224).unwrap().hash(input)
+    }
+}
+
+impl HashFunction for JH224 {
+    fn hash(&self, input: &Message) -> Digest {
+        JH224::hash(self, input)
+    }
+
+    fn block_size(&self) -> usize {
+        64
+    }
+}
+
+pub struct JH256;
+
+impl JH256 {
+    pub fn hash(&self, input: &Message) -> Digest {
+        JH::new(256).unwrap().hash(input)
+    }
+}
+
+impl HashFunction for JH256 {
+    fn hash(&self, input: &Message) -> Digest {
+        JH256::hash(self, input)
+    }
+
+    fn block_size(&self) -> usize {
+        64
+    }
+}
+
+pub struct JH384;
+
+impl JH384 {
+    pub fn hash(&self, input: &Message) -> Digest {
+        JH::new(384).unwrap().hash(input)
+    }
+}
+
+impl HashFunction for JH384 {
+    fn hash(&self, input: &Message) -> Digest {
+        JH384::hash(self, input)
+    }
+
+    fn block_size(&self) -> usize {
+        64
+    }
+}
+
+pub struct JH512;
+
+impl JH512 {
+    pub fn hash(&self, input: &Message) -> Digest {
+        JH::new(512).unwrap().hash(input)
+    }
+}
+
+impl HashFunction for JH512 {
+    fn hash(&self, input: &Message) -> Digest {
+        JH512::hash(self, input)
+    }
+
+    fn block_size(&self) -> usize {
+        64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jh_on_empty_message() {
+        let input = Message::from_string("");
+
+        assert_eq!(
+            JH224.hash(&input).to_hex(),
+            "2c99df889b019309051c60fecc2bd285a774940e43175b76b2626630"
+        );
+        assert_eq!(
+            JH256.hash(&input).to_hex(),
+            "46e64619c18bb0a92a5e87185a47eef83ca747b8fcc8e1412921357e326df434"
+        );
+        assert_eq!(
+            JH384.hash(&input).to_hex(),
+            "2fe5f71b1b3290d3c017fb3c1a4d02a5cbeb03a0476481e25082434a881994b0ff99e078d2c16b105ad069b569315328"
+        );
+        assert_eq!(
+            JH512.hash(&input).to_hex(),
+            "90ecf2f76f9d2c8017d979ad5ab96b87d58fc8fc4b83060f3f900774faa2c8fabe69c5f4ff1ec2b61d6b316941cedee117fb04b1f4c5bc1b919ae841c50eec4f"
+        );
+    }
+
+    #[test]
+    fn test_jh_on_quick_brown_fox() {
+        let input = Message::from_string("The quick brown fox jumps over the lazy dog");
+
+        assert_eq!(
+            JH224.hash(&input).to_hex(),
+            "bb21255e4a6bcbd3ddbf8694df2e7f41b74a69c1a7e1c2d36a3fd405"
+        );
+        assert_eq!(
+            JH256.hash(&input).to_hex(),
+            "6a049fed5fc6874acfdc4a08b568a4f8cbac27de933496f031015b38961608a0"
+        );
+        assert_eq!(
+            JH384.hash(&input).to_hex(),
+            "de44fe5f835f5518c603aec9d67363466d9f3a5b54d4cfbd4083b055f95a21a2562abaa59b830b3bc4e023d0b52a1268"
+        );
+        assert_eq!(
+            JH512.hash(&input).to_hex(),
+            "043f14e7c0775e7b1ef5ad657b1e858250b21e2e61fd699783f8634cb86f3ff938451cabd0c8cdae91d4f659d3f9f6f654f1bfedca117ffba735c15fedda47a3"
+        );
+    }
+
+    #[test]
+    fn test_jh_multi_block_message() {
+        let buffer: Vec<u8> = (0..=255).cycle().take(512).collect();
+        let input = Message::from_slice(&buffer);
+
+        assert_eq!(
+            JH256.hash(&input).to_hex(),
+            "5f3d747853af76eceef0deb4911a68e7561873f32415d8ea6daf1c230ae304da"
+        );
+        assert_eq!(
+            JH512.hash(&input).to_hex(),
+            "765ed55ade5deffa71af059fd34b2aa05899f207f6764227a1257cd01613c642a290f44a249f3566cf4d1e6c4714ec32e99a470387f6333ccbce326e8c9ae993"
+        );
+    }
+
+    #[test]
+    fn test_jh_custom_digest_size() {
+        let input = Message::from_string("abc");
+
+        assert_eq!(
+            JH::new(160).unwrap().hash(&input).to_hex(),
+            "298d54509d49f7eeae1dbbc5d1ca87403d418ad9"
+        );
+        assert!(JH::new(0).is_err());
+        assert!(JH::new(100).is_err());
+        assert!(JH::new(520).is_err());
+    }
+}
@@ -1,4 +1,4 @@
-use crate::hash::{Digest, Message};
+use crate::hash::{Digest, HashFunction, Message};
 
 const S: [u8; 256] = [
     41, 46, 67, 201, 162, 216, 124, 1, 61, 54, 84, 161, 236, 240, 6, 19, 98, 167, 5, 243, 192, 199,
@@ -66,6 +66,16 @@ impl MD2 {
     }
 }
 
+impl HashFunction for MD2 {
+    fn hash(&self, input: &Message) -> Digest {
+        MD2::hash(self, input)
+    }
+
+    fn block_size(&self) -> usize {
+        16
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,6 +1,6 @@
 use std::collections::VecDeque;
 
-use crate::hash::{Digest, Endianness, Message};
+use crate::hash::{Digest, Endianness, HashFunction, Message};
 
 const INIT_A: u32 = 0x67_45_23_01;
 const INIT_B: u32 = 0xEF_CD_AB_89;
@@ -89,6 +89,16 @@ impl MD4 {
     }
 }
 
+impl HashFunction for MD4 {
+    fn hash(&self, input: &Message) -> Digest {
+        MD4::hash(self, input)
+    }
+
+    fn block_size(&self) -> usize {
+        64
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
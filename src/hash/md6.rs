@@ -1,4 +1,5 @@
-use crate::hash::{Digest, Endianness, Message};
+use crate::hash::{Digest, Endianness, HashFunction, Message};
+use crate::secret;
 
 const WORD_LENGTH: usize = 64;
 
@@ -46,6 +47,13 @@ impl MD6Key {
     }
 }
 
+impl Drop for MD6Key {
+    fn drop(&mut self) {
+        secret::zeroize(&mut self.key);
+        secret::zeroize_value(&mut self.key_len);
+    }
+}
+
 fn build_v(r: usize, mode: usize, z: u64, p: usize, key_len: usize, d: usize) -> u64 {
     (r as u64) << 48
         | (mode as u64) << 40
@@ -160,6 +168,16 @@ impl MD6 {
     }
 }
 
+impl HashFunction for MD6 {
+    fn hash(&self, input: &Message) -> Digest {
+        MD6::hash(self, input)
+    }
+
+    fn block_size(&self) -> usize {
+        512
+    }
+}
+
 pub struct MD6_160 {
     md6: MD6,
 }
@@ -174,6 +192,16 @@ impl MD6_160 {
     }
 }
 
+impl HashFunction for MD6_160 {
+    fn hash(&self, input: &Message) -> Digest {
+        MD6_160::hash(self, input)
+    }
+
+    fn block_size(&self) -> usize {
+        512
+    }
+}
+
 pub struct MD6_224 {
     md6: MD6,
 }
@@ -188,6 +216,16 @@ impl MD6_224 {
     }
 }
 
+impl HashFunction for MD6_224 {
+    fn hash(&self, input: &Message) -> Digest {
+        MD6_224::hash(self, input)
+    }
+
+    fn block_size(&self) -> usize {
+        512
+    }
+}
+
 pub struct MD6_256 {
     md6: MD6,
 }
@@ -202,6 +240,16 @@ impl MD6_256 {
     }
 }
 
+impl HashFunction for MD6_256 {
+    fn hash(&self, input: &Message) -> Digest {
+        MD6_256::hash(self, input)
+    }
+
+    fn block_size(&self) -> usize {
+        512
+    }
+}
+
 pub struct MD6_384 {
     md6: MD6,
 }
@@ -216,6 +264,16 @@ impl MD6_384 {
     }
 }
 
+impl HashFunction for MD6_384 {
+    fn hash(&self, input: &Message) -> Digest {
+        MD6_384::hash(self, input)
+    }
+
+    fn block_size(&self) -> usize {
+        512
+    }
+}
+
 pub struct MD6_512 {
     md6: MD6,
 }
@@ -230,6 +288,16 @@ impl MD6_512 {
     }
 }
 
+impl HashFunction for MD6_512 {
+    fn hash(&self, input: &Message) -> Digest {
+        MD6_512::hash(self, input)
+    }
+
+    fn block_size(&self) -> usize {
+        512
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
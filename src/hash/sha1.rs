@@ -1,4 +1,4 @@
-use crate::hash::{Digest, Endianness, Message};
+use crate::hash::{Digest, Endianness, HashFunction, Message};
 
 const H0: u32 = 0x67452301;
 const H1: u32 = 0xEFCDAB89;
@@ -95,6 +95,16 @@ impl SHA1 {
     }
 }
 
+impl HashFunction for SHA1 {
+    fn hash(&self, input: &Message) -> Digest {
+        SHA1::hash(self, input)
+    }
+
+    fn block_size(&self) -> usize {
+        64
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,6 +1,6 @@
 use itertools::iproduct;
 
-use crate::hash::{Digest, Endianness, Message};
+use crate::hash::{Digest, Endianness, HashFunction, Message};
 
 const RHO_TABLE: [[u32; 5]; 5] = [
     [0, 36, 3, 41, 18],
@@ -147,6 +147,16 @@ impl SHA3_224 {
     }
 }
 
+impl HashFunction for SHA3_224 {
+    fn hash(&self, input: &Message) -> Digest {
+        SHA3_224::hash(self, input)
+    }
+
+    fn block_size(&self) -> usize {
+        self.rate
+    }
+}
+
 pub struct SHA3_256 {
     rate: usize,
 }
@@ -166,6 +176,16 @@ impl SHA3_256 {
     }
 }
 
+impl HashFunction for SHA3_256 {
+    fn hash(&self, input: &Message) -> Digest {
+        SHA3_256::hash(self, input)
+    }
+
+    fn block_size(&self) -> usize {
+        self.rate
+    }
+}
+
 pub struct SHA3_384 {
     rate: usize,
 }
@@ -192,6 +212,16 @@ impl SHA3_384 {
     }
 }
 
+impl HashFunction for SHA3_384 {
+    fn hash(&self, input: &Message) -> Digest {
+        SHA3_384::hash(self, input)
+    }
+
+    fn block_size(&self) -> usize {
+        self.rate
+    }
+}
+
 pub struct SHA3_512 {
     rate: usize,
 }
@@ -220,6 +250,16 @@ impl SHA3_512 {
     }
 }
 
+impl HashFunction for SHA3_512 {
+    fn hash(&self, input: &Message) -> Digest {
+        SHA3_512::hash(self, input)
+    }
+
+    fn block_size(&self) -> usize {
+        self.rate
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,4 +1,4 @@
-use crate::hash::{Digest, Endianness, Message};
+use crate::hash::{Digest, Endianness, HashFunction, Message};
 
 const K: [u64; 80] = [
     0x428a2f98d728ae22,
@@ -198,6 +198,16 @@ impl SHA384 {
     }
 }
 
+impl HashFunction for SHA384 {
+    fn hash(&self, input: &Message) -> Digest {
+        SHA384::hash(self, input)
+    }
+
+    fn block_size(&self) -> usize {
+        128
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,4 +1,4 @@
-use crate::hash::{Digest, Endianness, Message};
+use crate::hash::{Digest, Endianness, HashFunction, Message};
 
 const K: [u64; 80] = [
     0x428a2f98d728ae22,
@@ -92,7 +92,9 @@ const H5: u64 = 0x9b05688c2b3e6c1f;
 const H6: u64 = 0x1f83d9abfb41bd6b;
 const H7: u64 = 0x5be0cd19137e2179;
 
-fn pad(input: &Vec<u8>) -> Vec<u64> {
+pub(crate) const IV: [u64; 8] = [H0, H1, H2, H3, H4, H5, H6, H7];
+
+fn pad(input: &[u8]) -> Vec<u64> {
     let input_length: u128 = input.len() as u128;
     let input_length_in_bits: u128 = input_length * 8;
     let length_be_bytes: [u8; 16] = input_length_in_bits.to_be_bytes();
@@ -145,57 +147,72 @@ fn ssig1(x: u64) -> u64 {
     x.rotate_right(19) ^ x.rotate_right(61) ^ (x >> 6)
 }
 
+pub(crate) fn hash_with_iv(input: &[u8], iv: &[u64; 8]) -> Vec<u64> {
+    let input: Vec<u64> = pad(input);
+    let mut h: Vec<u64> = iv.to_vec();
+
+    for block in input.chunks(16) {
+        let mut w: Vec<u64> = block.to_vec();
+
+        for t in 16..80 {
+            w.push(
+                ssig1(w[t - 2])
+                    .wrapping_add(w[t - 7])
+                    .wrapping_add(ssig0(w[t - 15]))
+                    .wrapping_add(w[t - 16]),
+            );
+        }
+
+        let mut a = h.clone();
+
+        for t in 0..80 {
+            let t1 = a[7]
+                .wrapping_add(bsig1(a[4]))
+                .wrapping_add(ch(a[4], a[5], a[6]))
+                .wrapping_add(K[t])
+                .wrapping_add(w[t]);
+            let t2 = bsig0(a[0]).wrapping_add(maj(a[0], a[1], a[2]));
+
+            a[7] = a[6];
+            a[6] = a[5];
+            a[5] = a[4];
+            a[4] = a[3].wrapping_add(t1);
+            a[3] = a[2];
+            a[2] = a[1];
+            a[1] = a[0];
+            a[0] = t1.wrapping_add(t2);
+        }
+        h[0] = h[0].wrapping_add(a[0]);
+        h[1] = h[1].wrapping_add(a[1]);
+        h[2] = h[2].wrapping_add(a[2]);
+        h[3] = h[3].wrapping_add(a[3]);
+        h[4] = h[4].wrapping_add(a[4]);
+        h[5] = h[5].wrapping_add(a[5]);
+        h[6] = h[6].wrapping_add(a[6]);
+        h[7] = h[7].wrapping_add(a[7]);
+    }
+    h
+}
+
 pub struct SHA512;
 
 impl SHA512 {
     pub fn hash(&self, input: &Message) -> Digest {
-        let input: Vec<u64> = pad(&input.buffer);
-        let mut h: Vec<u64> = vec![H0, H1, H2, H3, H4, H5, H6, H7];
-
-        for block in input.chunks(16) {
-            let mut w: Vec<u64> = block.to_vec();
-
-            for t in 16..80 {
-                w.push(
-                    ssig1(w[t - 2])
-                        .wrapping_add(w[t - 7])
-                        .wrapping_add(ssig0(w[t - 15]))
-                        .wrapping_add(w[t - 16]),
-                );
-            }
-
-            let mut a = h.clone();
-
-            for t in 0..80 {
-                let t1 = a[7]
-                    .wrapping_add(bsig1(a[4]))
-                    .wrapping_add(ch(a[4], a[5], a[6]))
-                    .wrapping_add(K[t])
-                    .wrapping_add(w[t]);
-                let t2 = bsig0(a[0]).wrapping_add(maj(a[0], a[1], a[2]));
-
-                a[7] = a[6];
-                a[6] = a[5];
-                a[5] = a[4];
-                a[4] = a[3].wrapping_add(t1);
-                a[3] = a[2];
-                a[2] = a[1];
-                a[1] = a[0];
-                a[0] = t1.wrapping_add(t2);
-            }
-            h[0] = h[0].wrapping_add(a[0]);
-            h[1] = h[1].wrapping_add(a[1]);
-            h[2] = h[2].wrapping_add(a[2]);
-            h[3] = h[3].wrapping_add(a[3]);
-            h[4] = h[4].wrapping_add(a[4]);
-            h[5] = h[5].wrapping_add(a[5]);
-            h[6] = h[6].wrapping_add(a[6]);
-            h[7] = h[7].wrapping_add(a[7]);
-        }
+        let h: Vec<u64> = hash_with_iv(&input.buffer, &IV);
         Digest::from_u64(&h, Endianness::Big)
     }
 }
 
+impl HashFunction for SHA512 {
+    fn hash(&self, input: &Message) -> Digest {
+        SHA512::hash(self, input)
+    }
+
+    fn block_size(&self) -> usize {
+        128
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,104 @@
+use crate::hash::sha512;
+use crate::hash::{Digest, Endianness, HashError, HashFunction, Message};
+
+const IV_MASK: u64 = 0xa5a5a5a5a5a5a5a5;
+
+/*
+Generates the initial hash value for SHA-512/t as described in FIPS 180-4,
+section 5.3.6. The SHA-512 initial hash value is XORed with a5a5...a5 and
+the result is used as the IV for hashing the string "SHA-512/t", where t is
+written in decimal.
+*/
+fn generate_iv(t: usize) -> [u64; 8] {
+    let mut iv: [u64; 8] = sha512::IV;
+    for word in iv.iter_mut() {
+        *word ^= IV_MASK;
+    }
+    let h: Vec<u64> = sha512::hash_with_iv(format!("SHA-512/{t}").as_bytes(), &iv);
+    h.try_into().unwrap()
+}
+
+pub struct SHA512T {
+    t: usize,
+    iv: [u64; 8],
+}
+
+impl SHA512T {
+    pub fn new(t: usize) -> Result<Self, HashError> {
+        if t == 0 || t >= 512 || t == 384 {
+            return Err(HashError::InvalidDigestSizeError);
+        }
+        Ok(Self {
+            t,
+            iv: generate_iv(t),
+        })
+    }
+
+    pub(crate) fn from_iv(t: usize, iv: [u64; 8]) -> Self {
+        Self { t, iv }
+    }
+
+    pub fn hash(&self, input: &Message) -> Digest {
+        let h: Vec<u64> = sha512::hash_with_iv(&input.buffer, &self.iv);
+        let mut digest =
+            Digest::from_u64_range(&h, Endianness::Big, 0..self.t.div_ceil(8)).unwrap();
+        if !self.t.is_multiple_of(8) {
+            let last = digest.buffer.len() - 1;
+            digest.buffer[last] &= 0xff << (8 - self.t % 8);
+        }
+        digest.digest_size = self.t;
+        digest
+    }
+}
+
+impl HashFunction for SHA512T {
+    fn hash(&self, input: &Message) -> Digest {
+        SHA512T::hash(self, input)
+    }
+
+    fn block_size(&self) -> usize {
+        128
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hash::{sha512_224, sha512_256};
+
+    #[test]
+    fn test_sha512_t_iv_generation() {
+        assert_eq!(generate_iv(224), sha512_224::IV);
+        assert_eq!(generate_iv(256), sha512_256::IV);
+    }
+
+    #[test]
+    fn test_sha512_t() {
+        let input = Message::from_string("abc");
+        let tests = [
+            (
+                224,
+                "4634270f707b6a54daae7530460842e20e37ed265ceee9a43e8924aa",
+            ),
+            (
+                256,
+                "53048e2681941ef99b2e29b76b4c7dabe4c2d0c634fc6d46e0e2f13107e7af23",
+            ),
+            (160, "0a74fe1b43eecbea62182658da8a68b8acef25bf"),
+            (12, "1ac0"),
+            (8, "c5"),
+            (1, "00"),
+        ];
+
+        for (t, expected) in tests {
+            assert_eq!(SHA512T::new(t).unwrap().hash(&input).to_hex(), expected);
+        }
+    }
+
+    #[test]
+    fn test_sha512_t_invalid_sizes() {
+        for t in [0, 384, 512, 1024] {
+            assert!(SHA512T::new(t).is_err());
+        }
+    }
+}
@@ -0,0 +1,508 @@
+use std::hash::{BuildHasher, Hasher};
+
+use crate::hash::{Digest, Endianness, HashError, Message};
+use crate::secret;
+
+const SIP_IV: [u64; 4] = [
+    0x736f6d6570736575,
+    0x646f72616e646f6d,
+    0x6c7967656e657261,
+    0x7465646279746573,
+];
+
+const HALF_SIP_IV: [u32; 2] = [0x6c796765, 0x74656462];
+
+fn sip_round(v: &mut [u64; 4]) {
+    v[0] = v[0].wrapping_add(v[1]);
+    v[1] = v[1].rotate_left(13) ^ v[0];
+    v[0] = v[0].rotate_left(32);
+    v[2] = v[2].wrapping_add(v[3]);
+    v[3] = v[3].rotate_left(16) ^ v[2];
+    v[0] = v[0].wrapping_add(v[3]);
+    v[3] = v[3].rotate_left(21) ^ v[0];
+    v[2] = v[2].wrapping_add(v[1]);
+    v[1] = v[1].rotate_left(17) ^ v[2];
+    v[2] = v[2].rotate_left(32);
+}
+
+fn half_sip_round(v: &mut [u32; 4]) {
+    v[0] = v[0].wrapping_add(v[1]);
+    v[1] = v[1].rotate_left(5) ^ v[0];
+    v[0] = v[0].rotate_left(16);
+    v[2] = v[2].wrapping_add(v[3]);
+    v[3] = v[3].rotate_left(8) ^ v[2];
+    v[0] = v[0].wrapping_add(v[3]);
+    v[3] = v[3].rotate_left(7) ^ v[0];
+    v[2] = v[2].wrapping_add(v[1]);
+    v[1] = v[1].rotate_left(13) ^ v[2];
+    v[2] = v[2].rotate_left(16);
+}
+
+/*
+Incremental SipHash state. Input that does not fill a whole 64-bit word
+is kept in the tail until more bytes arrive or the state is finalized,
+which lets the same code back both the one-shot hash and the Hasher.
+The tail is a fixed buffer so that Hasher::write never allocates.
+*/
+#[derive(Clone)]
+struct SipState {
+    v: [u64; 4],
+    c_rounds: usize,
+    d_rounds: usize,
+    tail: [u8; 8],
+    tail_length: usize,
+    length: usize,
+}
+
+impl SipState {
+    fn new(k0: u64, k1: u64, c_rounds: usize, d_rounds: usize, output_size: usize) -> Self {
+        let mut v: [u64; 4] = [
+            k0 ^ SIP_IV[0],
+            k1 ^ SIP_IV[1],
+            k0 ^ SIP_IV[2],
+            k1 ^ SIP_IV[3],
+        ];
+        if output_size == 128 {
+            v[1] ^= 0xee;
+        }
+        Self {
+            v,
+            c_rounds,
+            d_rounds,
+            tail: [0; 8],
+            tail_length: 0,
+            length: 0,
+        }
+    }
+
+    fn compress(&mut self, m: u64) {
+        self.v[3] ^= m;
+        for _ in 0..self.c_rounds {
+            sip_round(&mut self.v);
+        }
+        self.v[0] ^= m;
+    }
+
+    fn update(&mut self, mut bytes: &[u8]) {
+        self.length += bytes.len();
+
+        if self.tail_length > 0 {
+            let needed: usize = (8 - self.tail_length).min(bytes.len());
+            self.tail[self.tail_length..self.tail_length + needed]
+                .copy_from_slice(&bytes[..needed]);
+            self.tail_length += needed;
+            bytes = &bytes[needed..];
+            if self.tail_length < 8 {
+                return;
+            }
+            self.compress(u64::from_le_bytes(self.tail));
+            self.tail_length = 0;
+        }
+
+        let mut words = bytes.chunks_exact(8);
+        for word in words.by_ref() {
+            self.compress(u64::from_le_bytes(word.try_into().unwrap()));
+        }
+        let remainder: &[u8] = words.remainder();
+        self.tail[..remainder.len()].copy_from_slice(remainder);
+        self.tail_length = remainder.len();
+    }
+
+    /* Returns both output words, the second one is only set for 128-bit outputs. */
+    fn finalize(&self, output_size: usize) -> [u64; 2] {
+        let mut state: SipState = self.clone();
+        let mut last_block: [u8; 8] = [0; 8];
+        last_block[..state.tail_length].copy_from_slice(&state.tail[..state.tail_length]);
+        last_block[7] = state.length as u8;
+        state.compress(u64::from_le_bytes(last_block));
+
+        state.v[2] ^= if output_size == 128 { 0xee } else { 0xff };
+        for _ in 0..state.d_rounds {
+            sip_round(&mut state.v);
+        }
+        let mut output: [u64; 2] = [state.v.iter().fold(0, |acc, v| acc ^ v), 0];
+
+        if output_size == 128 {
+            state.v[1] ^= 0xdd;
+            for _ in 0..state.d_rounds {
+                sip_round(&mut state.v);
+            }
+            output[1] = state.v.iter().fold(0, |acc, v| acc ^ v);
+        }
+        output
+    }
+}
+
+impl Drop for SipState {
+    fn drop(&mut self) {
+        secret::zeroize_value(&mut self.v);
+        secret::zeroize_value(&mut self.tail);
+    }
+}
+
+fn split_key(key: &[u8]) -> Result<(u64, u64), HashError> {
+    if key.len() != 16 {
+        return Err(HashError::InvalidKeyLengthError);
+    }
+    Ok((
+        u64::from_le_bytes(key[..8].try_into().unwrap()),
+        u64::from_le_bytes(key[8..].try_into().unwrap()),
+    ))
+}
+
+pub struct SipHash {
+    k0: u64,
+    k1: u64,
+    c_rounds: usize,
+    d_rounds: usize,
+    output_size: usize,
+}
+
+impl SipHash {
+    pub fn new(
+        key: &[u8],
+        c_rounds: usize,
+        d_rounds: usize,
+        output_size: usize,
+    ) -> Result<Self, HashError> {
+        let (k0, k1) = split_key(key)?;
+        if !matches!(output_size, 64 | 128) {
+            return Err(HashError::InvalidDigestSizeError);
+        }
+        Ok(Self {
+            k0,
+            k1,
+            c_rounds,
+            d_rounds,
+            output_size,
+        })
+    }
+
+    pub fn hash(&self, input: &Message) -> Digest {
+        let mut state: SipState = SipState::new(
+            self.k0,
+            self.k1,
+            self.c_rounds,
+            self.d_rounds,
+            self.output_size,
+        );
+        state.update(&input.buffer);
+        let output: [u64; 2] = state.finalize(self.output_size);
+        Digest::from_u64(&output[..self.output_size / 64], Endianness::Little)
+    }
+}
+
+impl Drop for SipHash {
+    fn drop(&mut self) {
+        secret::zeroize_value(&mut self.k0);
+        secret::zeroize_value(&mut self.k1);
+    }
+}
+
+pub struct SipHash24 {
+    siphash: SipHash,
+}
+
+impl SipHash24 {
+    pub fn new(key: &[u8]) -> Result<Self, HashError> {
+        Ok(Self {
+            siphash: SipHash::new(key, 2, 4, 64)?,
+        })
+    }
+
+    pub fn hash(&self, input: &Message) -> Digest {
+        self.siphash.hash(input)
+    }
+}
+
+pub struct SipHash13 {
+    siphash: SipHash,
+}
+
+impl SipHash13 {
+    pub fn new(key: &[u8]) -> Result<Self, HashError> {
+        Ok(Self {
+            siphash: SipHash::new(key, 1, 3, 64)?,
+        })
+    }
+
+    pub fn hash(&self, input: &Message) -> Digest {
+        self.siphash.hash(input)
+    }
+}
+
+/*
+HalfSipHash works on 32-bit words with a 64-bit key and produces either
+a 32-bit or a 64-bit output. Only the second and fourth words of the
+state are folded into the output.
+*/
+pub struct HalfSipHash {
+    k0: u32,
+    k1: u32,
+    c_rounds: usize,
+    d_rounds: usize,
+    output_size: usize,
+}
+
+impl HalfSipHash {
+    pub fn new(
+        key: &[u8],
+        c_rounds: usize,
+        d_rounds: usize,
+        output_size: usize,
+    ) -> Result<Self, HashError> {
+        if key.len() != 8 {
+            return Err(HashError::InvalidKeyLengthError);
+        }
+        if !matches!(output_size, 32 | 64) {
+            return Err(HashError::InvalidDigestSizeError);
+        }
+        Ok(Self {
+            k0: u32::from_le_bytes(key[..4].try_into().unwrap()),
+            k1: u32::from_le_bytes(key[4..].try_into().unwrap()),
+            c_rounds,
+            d_rounds,
+            output_size,
+        })
+    }
+
+    pub fn hash(&self, input: &Message) -> Digest {
+        let mut v: [u32; 4] = [
+            self.k0,
+            self.k1,
+            self.k0 ^ HALF_SIP_IV[0],
+            self.k1 ^ HALF_SIP_IV[1],
+        ];
+        if self.output_size == 64 {
+            v[1] ^= 0xee;
+        }
+
+        let mut last_block: [u8; 4] = [0; 4];
+        let chunks = input.buffer.chunks_exact(4);
+        let remainder: &[u8] = chunks.remainder();
+        last_block[..remainder.len()].copy_from_slice(remainder);
+        last_block[3] = input.buffer.len() as u8;
+
+        let words = chunks
+            .map(|chunk| u32::from_le_bytes(chunk.try_into().unwrap()))
+            .chain([u32::from_le_bytes(last_block)]);
+        for m in words {
+            v[3] ^= m;
+            for _ in 0..self.c_rounds {
+                half_sip_round(&mut v);
+            }
+            v[0] ^= m;
+        }
+
+        v[2] ^= if self.output_size == 64 { 0xee } else { 0xff };
+        for _ in 0..self.d_rounds {
+            half_sip_round(&mut v);
+        }
+        let mut output: Vec<u32> = vec![v[1] ^ v[3]];
+
+        if self.output_size == 64 {
+            v[1] ^= 0xdd;
+            for _ in 0..self.d_rounds {
+                half_sip_round(&mut v);
+            }
+            output.push(v[1] ^ v[3]);
+        }
+        Digest::from_u32(&output, Endianness::Little)
+    }
+}
+
+impl Drop for HalfSipHash {
+    fn drop(&mut self) {
+        secret::zeroize_value(&mut self.k0);
+        secret::zeroize_value(&mut self.k1);
+    }
+}
+
+/*
+A std::hash::Hasher computing SipHash-2-4 with a 64-bit output, so keyed
+hashing can back the standard collections. The keys are usually chosen
+at random by RandomSipState.
+*/
+#[derive(Clone)]
+pub struct SipHasher {
+    state: SipState,
+}
+
+impl SipHasher {
+    pub fn new_with_keys(k0: u64, k1: u64) -> Self {
+        Self {
+            state: SipState::new(k0, k1, 2, 4, 64),
+        }
+    }
+
+    pub fn new_with_key(key: &[u8]) -> Result<Self, HashError> {
+        let (k0, k1) = split_key(key)?;
+        Ok(Self::new_with_keys(k0, k1))
+    }
+}
+
+impl Hasher for SipHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        self.state.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        self.state.finalize(64)[0]
+    }
+}
+
+/*
+BuildHasher drawing a fresh random key on creation, meant for HashMaps
+and HashSets whose keys are attacker controlled.
+*/
+#[derive(Clone)]
+pub struct RandomSipState {
+    k0: u64,
+    k1: u64,
+}
+
+impl RandomSipState {
+    pub fn new() -> Self {
+        Self {
+            k0: rand::random(),
+            k1: rand::random(),
+        }
+    }
+}
+
+impl Default for RandomSipState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for RandomSipState {
+    type Hasher = SipHasher;
+
+    fn build_hasher(&self) -> SipHasher {
+        SipHasher::new_with_keys(self.k0, self.k1)
+    }
+}
+
+impl Drop for RandomSipState {
+    fn drop(&mut self) {
+        secret::zeroize_value(&mut self.k0);
+        secret::zeroize_value(&mut self.k1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    const KEY: [u8; 16] = [
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e,
+        0x0f,
+    ];
+
+    fn sequence(length: u8) -> Message {
+        Message::from_slice(&(0..length).collect::<Vec<u8>>())
+    }
+
+    #[test]
+    fn test_siphash24_on_reference_vectors() {
+        let hasher = SipHash24::new(&KEY).unwrap();
+
+        assert_eq!(hasher.hash(&sequence(0)).to_hex(), "310e0edd47db6f72");
+        assert_eq!(hasher.hash(&sequence(7)).to_hex(), "37d1018bf50002ab");
+        assert_eq!(hasher.hash(&sequence(15)).to_hex(), "e545be4961ca29a1");
+        assert_eq!(hasher.hash(&sequence(64)).to_hex(), "d8ca02850bc4d2ac");
+    }
+
+    #[test]
+    fn test_siphash24_128_on_reference_vectors() {
+        let hasher = SipHash::new(&KEY, 2, 4, 128).unwrap();
+
+        assert_eq!(
+            hasher.hash(&sequence(0)).to_hex(),
+            "a3817f04ba25a8e66df67214c7550293"
+        );
+        assert_eq!(
+            hasher.hash(&sequence(7)).to_hex(),
+            "a1f1ebbed8dbc153c0b84aa61ff08239"
+        );
+        assert_eq!(
+            hasher.hash(&sequence(64)).to_hex(),
+            "1eaf077dc0d4cd3f8cad4d383658a74b"
+        );
+    }
+
+    #[test]
+    fn test_siphash13() {
+        let hasher = SipHash13::new(&KEY).unwrap();
+
+        assert_eq!(hasher.hash(&sequence(7)).to_hex(), "4011b19b987d92d3");
+        assert_eq!(hasher.hash(&sequence(64)).to_hex(), "65604a4bec9779f1");
+        assert_eq!(
+            SipHash13::new(&[0; 16])
+                .unwrap()
+                .hash(&sequence(15))
+                .to_hex(),
+            "eac991bb25b70ef3"
+        );
+        assert_eq!(
+            SipHash::new(&KEY, 1, 3, 128)
+                .unwrap()
+                .hash(&sequence(64))
+                .to_hex(),
+            "1253def24b4aa5364ec8a759ba6a66c2"
+        );
+    }
+
+    #[test]
+    fn test_half_siphash_on_reference_vectors() {
+        let hasher_32 = HalfSipHash::new(&KEY[..8], 2, 4, 32).unwrap();
+        let hasher_64 = HalfSipHash::new(&KEY[..8], 2, 4, 64).unwrap();
+
+        assert_eq!(hasher_32.hash(&sequence(0)).to_hex(), "a9359f5b");
+        assert_eq!(hasher_32.hash(&sequence(11)).to_hex(), "08083050");
+        assert_eq!(hasher_64.hash(&sequence(0)).to_hex(), "218d1f59b9b83cc8");
+        assert_eq!(hasher_64.hash(&sequence(11)).to_hex(), "ae26333994ddcd48");
+    }
+
+    #[test]
+    fn test_siphash_invalid_parameters() {
+        assert!(SipHash24::new(&KEY[..15]).is_err());
+        assert!(SipHash::new(&KEY, 2, 4, 32).is_err());
+        assert!(HalfSipHash::new(&KEY, 2, 4, 32).is_err());
+        assert!(HalfSipHash::new(&KEY[..8], 2, 4, 128).is_err());
+        assert!(SipHasher::new_with_key(&KEY[..8]).is_err());
+    }
+
+    #[test]
+    fn test_sip_hasher_matches_siphash24() {
+        for length in 0..64 {
+            let input = sequence(length);
+            let expected = SipHash24::new(&KEY).unwrap().hash(&input);
+
+            for chunk_size in [1, 3, 5, 8, 13, 64] {
+                let mut hasher = SipHasher::new_with_key(&KEY).unwrap();
+                for chunk in input.to_slice().chunks(chunk_size) {
+                    hasher.write(chunk);
+                }
+                assert_eq!(hasher.finish().to_le_bytes(), expected.to_slice());
+            }
+        }
+    }
+
+    #[test]
+    fn test_random_sip_state_with_hash_map() {
+        let mut map: HashMap<String, usize, RandomSipState> =
+            HashMap::with_hasher(RandomSipState::new());
+        for i in 0..100 {
+            map.insert(i.to_string(), i);
+        }
+
+        assert_eq!(map.len(), 100);
+        assert_eq!(map.get("42"), Some(&42));
+
+        let state = RandomSipState::new();
+        assert_eq!(state.hash_one("abc"), state.hash_one("abc"));
+    }
+}
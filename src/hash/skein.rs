@@ -0,0 +1,297 @@
+use crate::cipher::threefish::Threefish;
+use crate::hash::{Digest, HashError, HashFunction, Message};
+use crate::secret;
+
+const TYPE_KEY: u64 = 0;
+const TYPE_CFG: u64 = 4;
+const TYPE_PRS: u64 = 8;
+const TYPE_MSG: u64 = 48;
+const TYPE_OUT: u64 = 63;
+
+const FIRST_FLAG: u64 = 1 << 62;
+const FINAL_FLAG: u64 = 1 << 63;
+
+const SCHEMA_ID: &[u8; 4] = b"SHA3";
+const VERSION: u16 = 1;
+
+/*
+Unique Block Iteration, the chaining mode of Skein. Every block is
+encrypted with Threefish keyed by the current chaining value and tweaked
+with the number of bytes processed so far, the type of the input and the
+first and final flags. The ciphertext is XORed with the block to form the
+next chaining value. An empty input is processed as a single zero block.
+*/
+fn ubi(g: &[u64], input: &[u8], block_type: u64) -> Vec<u64> {
+    let block_size: usize = g.len() * 8;
+    let mut g: Vec<u64> = g.to_vec();
+    let num_blocks: usize = input.len().div_ceil(block_size).max(1);
+    let mut position: u128 = 0;
+
+    for i in 0..num_blocks {
+        let start: usize = i * block_size;
+        let end: usize = input.len().min(start + block_size);
+        let mut block: Vec<u8> = input[start..end].to_vec();
+        position += block.len() as u128;
+        block.resize(block_size, 0x00);
+
+        let mut t1: u64 = (block_type << 56) | (position >> 64) as u64;
+        if i == 0 {
+            t1 |= FIRST_FLAG;
+        }
+        if i == num_blocks - 1 {
+            t1 |= FINAL_FLAG;
+        }
+
+        let m: Vec<u64> = block
+            .chunks_exact(8)
+            .map(|chunk| u64::from_le_bytes(chunk.try_into().unwrap()))
+            .collect();
+        let c: Vec<u64> = Threefish::from_words(&g, [position as u64, t1]).encrypt_words(&m);
+        g = c.iter().zip(&m).map(|(c, m)| c ^ m).collect();
+    }
+    g
+}
+
+pub struct Skein {
+    state_size: usize,
+    d: usize,
+    key: Vec<u8>,
+    personalization: Vec<u8>,
+}
+
+impl Skein {
+    pub fn new(state_size: usize, d: usize) -> Result<Self, HashError> {
+        if !matches!(state_size, 256 | 512 | 1024) {
+            return Err(HashError::InvalidStateSizeError);
+        }
+        if d == 0 {
+            return Err(HashError::InvalidDigestSizeError);
+        }
+        Ok(Self {
+            state_size,
+            d,
+            key: vec![],
+            personalization: vec![],
+        })
+    }
+
+    pub fn with_key(mut self, key: &[u8]) -> Self {
+        self.key = key.to_vec();
+        self
+    }
+
+    pub fn with_personalization(mut self, personalization: &[u8]) -> Self {
+        self.personalization = personalization.to_vec();
+        self
+    }
+
+    fn config(&self) -> Vec<u8> {
+        let mut config: Vec<u8> = Vec::with_capacity(32);
+        config.extend_from_slice(SCHEMA_ID);
+        config.extend_from_slice(&VERSION.to_le_bytes());
+        config.extend_from_slice(&[0; 2]);
+        config.extend_from_slice(&(self.d as u64).to_le_bytes());
+        config.resize(32, 0x00);
+        config
+    }
+
+    pub fn hash(&self, input: &Message) -> Digest {
+        let mut g: Vec<u64> = vec![0; self.state_size / 64];
+        if !self.key.is_empty() {
+            g = ubi(&g, &self.key, TYPE_KEY);
+        }
+        g = ubi(&g, &self.config(), TYPE_CFG);
+        if !self.personalization.is_empty() {
+            g = ubi(&g, &self.personalization, TYPE_PRS);
+        }
+        g = ubi(&g, &input.buffer, TYPE_MSG);
+
+        let num_bytes: usize = self.d.div_ceil(8);
+        let mut output: Vec<u8> = Vec::with_capacity(num_bytes);
+        let mut counter: u64 = 0;
+        while output.len() < num_bytes {
+            let block: Vec<u64> = ubi(&g, &counter.to_le_bytes(), TYPE_OUT);
+            output.extend(block.iter().flat_map(|word| word.to_le_bytes()));
+            counter += 1;
+        }
+        output.truncate(num_bytes);
+        if !self.d.is_multiple_of(8) {
+            output[num_bytes - 1] &= 0xff << (8 - self.d % 8);
+        }
+
+        let mut digest: Digest = Digest::from_u8(&output);
+        digest.digest_size = self.d;
+        digest
+    }
+}
+
+impl HashFunction for Skein {
+    fn hash(&self, input: &Message) -> Digest {
+        Skein::hash(self, input)
+    }
+
+    fn block_size(&self) -> usize {
+        self.state_size / 8
+    }
+}
+
+impl Drop for Skein {
+    fn drop(&mut self) {
+        secret::zeroize(&mut self.key);
+    }
+}
+
+pub struct Skein256;
+
+impl Skein256 {
+    pub fn hash(&self, input: &Message) -> Digest {
+        Skein::new(256, 256).unwrap().hash(input)
+    }
+}
+
+impl HashFunction for Skein256 {
+    fn hash(&self, input: &Message) -> Digest {
+        Skein256::hash(self, input)
+    }
+
+    fn block_size(&self) -> usize {
+        32
+    }
+}
+
+pub struct Skein512;
+
+impl Skein512 {
+    pub fn hash(&self, input: &Message) -> Digest {
+        Skein::new(512, 512).unwrap().hash(input)
+    }
+}
+
+impl HashFunction for Skein512 {
+    fn hash(&self, input: &Message) -> Digest {
+        Skein512::hash(self, input)
+    }
+
+    fn block_size(&self) -> usize {
+        64
+    }
+}
+
+pub struct Skein1024;
+
+impl Skein1024 {
+    pub fn hash(&self, input: &Message) -> Digest {
+        Skein::new(1024, 1024).unwrap().hash(input)
+    }
+}
+
+impl HashFunction for Skein1024 {
+    fn hash(&self, input: &Message) -> Digest {
+        Skein1024::hash(self, input)
+    }
+
+    fn block_size(&self) -> usize {
+        128
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_skein_on_empty_message() {
+        let input = Message::from_string("");
+
+        assert_eq!(
+            Skein256.hash(&input).to_hex(),
+            "c8877087da56e072870daa843f176e9453115929094c3a40c463a196c29bf7ba"
+        );
+        assert_eq!(
+            Skein::new(512, 256).unwrap().hash(&input).to_hex(),
+            "39ccc4554a8b31853b9de7a1fe638a24cce6b35a55f2431009e18780335d2621"
+        );
+        assert_eq!(
+            Skein512.hash(&input).to_hex(),
+            "bc5b4c50925519c290cc634277ae3d6257212395cba733bbad37a4af0fa06af41fca7903d06564fea7a2d3730dbdb80c1f85562dfcc070334ea4d1d9e72cba7a"
+        );
+        assert_eq!(
+            Skein1024.hash(&input).to_hex(),
+            "0fff9563bb3279289227ac77d319b6fff8d7e9f09da1247b72a0a265cd6d2a62645ad547ed8193db48cff847c06494a03f55666d3b47eb4c20456c9373c86297d630d5578ebd34cb40991578f9f52b18003efa35d3da6553ff35db91b81ab890bec1b189b7f52cb2a783ebb7d823d725b0b4a71f6824e88f68f982eefc6d19c6"
+        );
+    }
+
+    #[test]
+    fn test_skein_on_single_byte() {
+        let input = Message::from_slice(&[0xff]);
+
+        assert_eq!(
+            Skein256.hash(&input).to_hex(),
+            "0b98dcd198ea0e50a7a244c444e25c23da30c10fc9a1f270a6637f1f34e67ed2"
+        );
+        assert_eq!(
+            Skein512.hash(&input).to_hex(),
+            "71b7bce6fe6452227b9ced6014249e5bf9a9754c3ad618ccc4e0aae16b316cc8ca698d864307ed3e80b6ef1570812ac5272dc409b5a012df2a579102f340617a"
+        );
+        assert_eq!(
+            Skein1024.hash(&input).to_hex(),
+            "e62c05802ea0152407cdd8787fda9e35703de862a4fbc119cff8590afe79250bccc8b3faf1bd2422ab5c0d263fb2f8afb3f796f048000381531b6f00d85161bc0fff4bef2486b1ebcd3773fabf50ad4ad5639af9040e3f29c6c931301bf79832e9da09857e831e82ef8b4691c235656515d437d2bda33bcec001c67ffde15ba8"
+        );
+    }
+
+    #[test]
+    fn test_skein_multi_block_message() {
+        let mut buffer: Vec<u8> = (0..=255).cycle().take(1024).collect();
+        buffer.push(b'x');
+
+        assert_eq!(
+            Skein512.hash(&Message::from_slice(&buffer)).to_hex(),
+            "0163abdbe1a597cd6ea7ae113611b5de62db25ff7b87600060d1a47bb3cf34b0ed59e6f9b18a961e920ad1ec9c98150f72e734fd4a550b32bec5bb9fad17c6c2"
+        );
+    }
+
+    #[test]
+    fn test_skein_output_lengths() {
+        let input = Message::from_string("abc");
+
+        assert_eq!(
+            Skein::new(512, 1000).unwrap().hash(&input).to_hex(),
+            "59e72ed3834618cdac0251e663252ce15c4d38ef1d4a497b048539186c4f2c0be1ee92280610980628535619ad89a7ae0d601f8b545ce511e1627f99e47b30622b27a2b5a1faa2334503144e91ebd3fa70408e559a145faf742378c3024541c725646f74d51f9636dc1f0545a2dbad3735d56f7e9d5497ec4ab011e611"
+        );
+        assert_eq!(Skein::new(256, 12).unwrap().hash(&input).to_hex(), "b650");
+    }
+
+    #[test]
+    fn test_skein_mac_and_personalization() {
+        let input = Message::from_string("abc");
+        let key: Vec<u8> = (0..32).collect();
+
+        assert_eq!(
+            Skein::new(512, 512).unwrap().with_key(&key).hash(&input).to_hex(),
+            "0f666d8f38844cebf0a7c3835e06e5a2944944955180948eb4d6775caab04beb2d8f2d0e16b6ac74369f64028f5840a6b3f4ad0eee9473d09db9059ba1cf6131"
+        );
+        assert_eq!(
+            Skein::new(512, 256)
+                .unwrap()
+                .with_personalization(b"20081031 abc@example.com skein/")
+                .hash(&input)
+                .to_hex(),
+            "e589161753c34aa6e0ea73f58da80fe77900c22fa265b424611d3cec89db657e"
+        );
+        assert_eq!(
+            Skein::new(256, 128)
+                .unwrap()
+                .with_key(b"key")
+                .with_personalization(b"app")
+                .hash(&Message::from_slice(&(0..100).collect::<Vec<u8>>()))
+                .to_hex(),
+            "d4b2a516ce29aecb9105023490154aed"
+        );
+    }
+
+    #[test]
+    fn test_skein_invalid_parameters() {
+        assert!(Skein::new(128, 256).is_err());
+        assert!(Skein::new(512, 0).is_err());
+    }
+}
@@ -0,0 +1,152 @@
+use crate::hash::{Digest, Endianness, HashFunction, Message};
+
+const IV: [u32; 8] = [
+    0x7380166f, 0x4914b2b9, 0x172442d7, 0xda8a0600, 0xa96f30bc, 0x163138aa, 0xe38dee4d, 0xb0fb0e4e,
+];
+
+const T0: u32 = 0x79cc4519;
+const T1: u32 = 0x7a879d8a;
+
+fn pad(input: &[u8]) -> Vec<u32> {
+    let input_length: u64 = input.len() as u64;
+    let input_length_in_bits: u64 = input_length * 8;
+    let length_be_bytes: [u8; 8] = input_length_in_bits.to_be_bytes();
+
+    let input_length_mod_64: u64 = input_length % 64;
+    let padding_length: u64 = match input_length_mod_64 {
+        56 => 64,
+        _ => (56 + 64 - input_length_mod_64) % 64,
+    };
+
+    let total_length = (input_length + padding_length + 8) as usize;
+    let mut buffer: Vec<u8> = Vec::with_capacity(total_length);
+
+    buffer.extend_from_slice(input);
+    buffer.push(0x80);
+    buffer.resize((input_length + padding_length) as usize, 0x00);
+    buffer.extend_from_slice(&length_be_bytes);
+
+    let mut words: Vec<u32> = Vec::new();
+
+    for chunk in buffer.chunks_exact(4) {
+        let word = u32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        words.push(word);
+    }
+
+    words
+}
+
+fn ff(j: usize, x: u32, y: u32, z: u32) -> u32 {
+    match j {
+        0..=15 => x ^ y ^ z,
+        _ => (x & y) | (x & z) | (y & z),
+    }
+}
+
+fn gg(j: usize, x: u32, y: u32, z: u32) -> u32 {
+    match j {
+        0..=15 => x ^ y ^ z,
+        _ => (x & y) | (!x & z),
+    }
+}
+
+fn t(j: usize) -> u32 {
+    match j {
+        0..=15 => T0,
+        _ => T1,
+    }
+}
+
+fn p0(x: u32) -> u32 {
+    x ^ x.rotate_left(9) ^ x.rotate_left(17)
+}
+
+fn p1(x: u32) -> u32 {
+    x ^ x.rotate_left(15) ^ x.rotate_left(23)
+}
+
+pub struct SM3;
+
+impl SM3 {
+    pub fn hash(&self, input: &Message) -> Digest {
+        let input: Vec<u32> = pad(&input.buffer);
+        let mut v: Vec<u32> = IV.to_vec();
+
+        for block in input.chunks(16) {
+            let mut w: Vec<u32> = block.to_vec();
+
+            for j in 16..68 {
+                w.push(
+                    p1(w[j - 16] ^ w[j - 9] ^ w[j - 3].rotate_left(15))
+                        ^ w[j - 13].rotate_left(7)
+                        ^ w[j - 6],
+                );
+            }
+
+            let w_prime: Vec<u32> = (0..64).map(|j| w[j] ^ w[j + 4]).collect();
+
+            let mut a = v.clone();
+
+            for j in 0..64 {
+                let ss1 = a[0]
+                    .rotate_left(12)
+                    .wrapping_add(a[4])
+                    .wrapping_add(t(j).rotate_left((j % 32) as u32))
+                    .rotate_left(7);
+                let ss2 = ss1 ^ a[0].rotate_left(12);
+                let tt1 = ff(j, a[0], a[1], a[2])
+                    .wrapping_add(a[3])
+                    .wrapping_add(ss2)
+                    .wrapping_add(w_prime[j]);
+                let tt2 = gg(j, a[4], a[5], a[6])
+                    .wrapping_add(a[7])
+                    .wrapping_add(ss1)
+                    .wrapping_add(w[j]);
+
+                a[3] = a[2];
+                a[2] = a[1].rotate_left(9);
+                a[1] = a[0];
+                a[0] = tt1;
+                a[7] = a[6];
+                a[6] = a[5].rotate_left(19);
+                a[5] = a[4];
+                a[4] = p0(tt2);
+            }
+            for (v, a) in v.iter_mut().zip(&a) {
+                *v ^= a;
+            }
+        }
+        Digest::from_u32(&v, Endianness::Big)
+    }
+}
+
+impl HashFunction for SM3 {
+    fn hash(&self, input: &Message) -> Digest {
+        SM3::hash(self, input)
+    }
+
+    fn block_size(&self) -> usize {
+        64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sm3_on_gbt32905_suite() {
+        let hasher = SM3;
+        let i1 = Message::from_string("abc");
+        let i2 = Message::from_string(&"abcd".repeat(16));
+
+        assert_eq!(
+            hasher.hash(&i1).to_hex(),
+            "66c7f0f462eeedd9d1f2d46bdc10e4e24167c4875cf2f7a2297da02b8f4ba8e0"
+        );
+        assert_eq!(
+            hasher.hash(&i2).to_hex(),
+            "debe9ff92275b8a138604889c18e5a4d6fdb70e5387e5765293dcba39c0c5732"
+        );
+    }
+}
@@ -0,0 +1,100 @@
+use std::{error, fmt};
+
+pub mod argon2;
+pub mod concat;
+pub mod hkdf;
+pub mod kbkdf;
+pub mod pbkdf2;
+pub mod scrypt;
+pub mod x963;
+
+#[derive(Debug)]
+pub enum KdfError {
+    InvalidBlockSizeError,
+    InvalidCostError,
+    InvalidCounterLengthError,
+    InvalidEncodingError,
+    InvalidIterationCountError,
+    InvalidKeyLengthError,
+    InvalidOutputLengthError,
+    InvalidParallelismError,
+    InvalidSaltLengthError,
+    WorkerThreadError,
+}
+
+impl fmt::Display for KdfError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KdfError::InvalidBlockSizeError => write!(f, "Invalid block size provided"),
+            KdfError::InvalidCostError => write!(f, "Invalid cost parameter provided"),
+            KdfError::InvalidCounterLengthError => write!(f, "Invalid counter length provided"),
+            KdfError::InvalidEncodingError => write!(f, "Invalid encoded string provided"),
+            KdfError::InvalidIterationCountError => {
+                write!(f, "Invalid iteration count provided")
+            }
+            KdfError::InvalidKeyLengthError => write!(f, "Invalid key length provided"),
+            KdfError::InvalidOutputLengthError => write!(f, "Invalid output length requested"),
+            KdfError::InvalidParallelismError => {
+                write!(f, "Invalid parallelization parameter provided")
+            }
+            KdfError::InvalidSaltLengthError => write!(f, "Invalid salt length provided"),
+            KdfError::WorkerThreadError => write!(f, "Worker thread could not be run"),
+        }
+    }
+}
+
+impl error::Error for KdfError {}
+
+/*
+Upper bounds on the parameters of a stored password hash, checked before
+anything is derived so that a hash planted in a user table cannot make
+the verifying process allocate or compute without limit. Memory is in
+bytes, passes are the full passes over that memory (the Argon2 time cost
+and the scrypt parallelization parameter, whose blocks are mixed one
+after another) and lanes are the Argon2 parallelism. The defaults allow
+1 GiB, 16 passes and 64 lanes, above what is recommended for logins.
+*/
+#[derive(Clone, Copy, Debug)]
+pub struct CostLimits {
+    max_memory: u64,
+    max_passes: u64,
+    max_lanes: u64,
+}
+
+impl CostLimits {
+    pub fn new() -> Self {
+        Self {
+            max_memory: 1 << 30,
+            max_passes: 16,
+            max_lanes: 64,
+        }
+    }
+
+    pub fn with_max_memory(mut self, bytes: u64) -> Self {
+        self.max_memory = bytes;
+        self
+    }
+
+    pub fn with_max_passes(mut self, passes: u64) -> Self {
+        self.max_passes = passes;
+        self
+    }
+
+    pub fn with_max_lanes(mut self, lanes: u64) -> Self {
+        self.max_lanes = lanes;
+        self
+    }
+
+    pub(crate) fn check(&self, memory: u64, passes: u64, lanes: u64) -> Result<(), KdfError> {
+        if memory > self.max_memory || passes > self.max_passes || lanes > self.max_lanes {
+            return Err(KdfError::InvalidCostError);
+        }
+        Ok(())
+    }
+}
+
+impl Default for CostLimits {
+    fn default() -> Self {
+        Self::new()
+    }
+}
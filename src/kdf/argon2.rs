@@ -0,0 +1,679 @@
+use std::thread;
+
+use crate::hash::blake2b::BLAKE2b;
+use crate::hash::{Digest, Message};
+use crate::kdf::{CostLimits, KdfError};
+use crate::password::phc::PhcString;
+use crate::secret;
+
+pub(crate) const VERSION: u32 = 0x13;
+const BLOCK_WORDS: usize = 128;
+const SYNC_POINTS: usize = 4;
+
+type Block = [u64; BLOCK_WORDS];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Argon2Variant {
+    Argon2d,
+    Argon2i,
+    Argon2id,
+}
+
+impl Argon2Variant {
+    fn type_code(&self) -> u32 {
+        match self {
+            Argon2Variant::Argon2d => 0,
+            Argon2Variant::Argon2i => 1,
+            Argon2Variant::Argon2id => 2,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Argon2Variant::Argon2d => "argon2d",
+            Argon2Variant::Argon2i => "argon2i",
+            Argon2Variant::Argon2id => "argon2id",
+        }
+    }
+}
+
+/*
+Variable-length hash H' built from BLAKE2b. Outputs longer than 64 bytes
+are produced by chaining 64-byte hashes and keeping the first half of
+each, except for the last one which is kept whole.
+*/
+fn h_prime(input: &[u8], length: usize) -> Vec<u8> {
+    let mut message: Message = Message::from_slice(&(length as u32).to_le_bytes());
+    message.extend_from_slice(input);
+    if length <= 64 {
+        return BLAKE2b::new(length * 8)
+            .unwrap()
+            .hash(&message)
+            .to_slice()
+            .to_vec();
+    }
+
+    let r: usize = length.div_ceil(32) - 2;
+    let mut output: Vec<u8> = Vec::with_capacity(length);
+    let mut v: Digest = BLAKE2b::new(512).unwrap().hash(&message);
+    output.extend_from_slice(&v.to_slice()[..32]);
+    for _ in 1..r {
+        v = BLAKE2b::new(512)
+            .unwrap()
+            .hash(&Message::from_slice(v.to_slice()));
+        output.extend_from_slice(&v.to_slice()[..32]);
+    }
+    let v: Digest = BLAKE2b::new((length - 32 * r) * 8)
+        .unwrap()
+        .hash(&Message::from_slice(v.to_slice()));
+    output.extend_from_slice(v.to_slice());
+    output
+}
+
+fn g_b(v: &mut Block, a: usize, b: usize, c: usize, d: usize) {
+    let f = |x: u64, y: u64| {
+        x.wrapping_add(y).wrapping_add(
+            2u64.wrapping_mul(x & 0xffffffff)
+                .wrapping_mul(y & 0xffffffff),
+        )
+    };
+    v[a] = f(v[a], v[b]);
+    v[d] = (v[d] ^ v[a]).rotate_right(32);
+    v[c] = f(v[c], v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(24);
+    v[a] = f(v[a], v[b]);
+    v[d] = (v[d] ^ v[a]).rotate_right(16);
+    v[c] = f(v[c], v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(63);
+}
+
+/*
+The BLAKE2b round with multiplications, applied to the 16 words whose
+indices are given. The block is an 8 x 8 matrix of 16-byte registers,
+each register holding two consecutive words.
+*/
+fn permute(v: &mut Block, indices: [usize; 16]) {
+    let mut x: Block = [0; BLOCK_WORDS];
+    for (i, &index) in indices.iter().enumerate() {
+        x[i] = v[index];
+    }
+    g_b(&mut x, 0, 4, 8, 12);
+    g_b(&mut x, 1, 5, 9, 13);
+    g_b(&mut x, 2, 6, 10, 14);
+    g_b(&mut x, 3, 7, 11, 15);
+    g_b(&mut x, 0, 5, 10, 15);
+    g_b(&mut x, 1, 6, 11, 12);
+    g_b(&mut x, 2, 7, 8, 13);
+    g_b(&mut x, 3, 4, 9, 14);
+    for (i, &index) in indices.iter().enumerate() {
+        v[index] = x[i];
+    }
+}
+
+/* Compression function G, R = X xor Y is permuted by rows then by columns. */
+fn compress(x: &Block, y: &Block) -> Block {
+    let mut r: Block = [0; BLOCK_WORDS];
+    for (r, (x, y)) in r.iter_mut().zip(x.iter().zip(y)) {
+        *r = x ^ y;
+    }
+    let mut z: Block = r;
+    for row in 0..8 {
+        let indices: [usize; 16] = std::array::from_fn(|i| 16 * row + i);
+        permute(&mut z, indices);
+    }
+    for column in 0..8 {
+        let indices: [usize; 16] = std::array::from_fn(|i| 2 * column + (i % 2) + 16 * (i / 2));
+        permute(&mut z, indices);
+    }
+    for (z, r) in z.iter_mut().zip(&r) {
+        *z ^= r;
+    }
+    z
+}
+
+fn block_from_bytes(bytes: &[u8]) -> Block {
+    let mut block: Block = [0; BLOCK_WORDS];
+    for (word, chunk) in block.iter_mut().zip(bytes.chunks_exact(8)) {
+        *word = u64::from_le_bytes(chunk.try_into().unwrap());
+    }
+    block
+}
+
+struct Position {
+    pass: usize,
+    lane: usize,
+    slice: usize,
+}
+
+/*
+Argon2 as specified in RFC 9106, version 0x13. The memory cost is given
+in KiB and rounded down to a multiple of 4 * lanes blocks, the time cost
+is the number of passes over the memory. Lanes are filled in parallel
+by at most as many threads as the machine runs at once, joined at each
+of the four synchronization points of a pass.
+*/
+pub struct Argon2 {
+    variant: Argon2Variant,
+    memory_cost: u32,
+    time_cost: u32,
+    lanes: u32,
+    secret: Vec<u8>,
+    associated_data: Vec<u8>,
+}
+
+impl Argon2 {
+    pub fn new(
+        variant: Argon2Variant,
+        memory_cost: u32,
+        time_cost: u32,
+        lanes: u32,
+    ) -> Result<Self, KdfError> {
+        if lanes == 0 || lanes >= 1 << 24 {
+            return Err(KdfError::InvalidParallelismError);
+        }
+        if time_cost == 0 {
+            return Err(KdfError::InvalidIterationCountError);
+        }
+        if (memory_cost as u64) < 8 * lanes as u64 {
+            return Err(KdfError::InvalidCostError);
+        }
+        Ok(Self {
+            variant,
+            memory_cost,
+            time_cost,
+            lanes,
+            secret: vec![],
+            associated_data: vec![],
+        })
+    }
+
+    pub fn with_secret(mut self, secret: &[u8]) -> Self {
+        self.secret = secret.to_vec();
+        self
+    }
+
+    pub fn with_associated_data(mut self, associated_data: &[u8]) -> Self {
+        self.associated_data = associated_data.to_vec();
+        self
+    }
+
+    pub fn variant(&self) -> Argon2Variant {
+        self.variant
+    }
+
+    pub fn memory_cost(&self) -> u32 {
+        self.memory_cost
+    }
+
+    pub fn time_cost(&self) -> u32 {
+        self.time_cost
+    }
+
+    pub fn lanes(&self) -> u32 {
+        self.lanes
+    }
+
+    fn initial_hash(&self, password: &[u8], salt: &[u8], length: usize) -> Vec<u8> {
+        let mut input: Message = Message::new();
+        for value in [
+            self.lanes,
+            length as u32,
+            self.memory_cost,
+            self.time_cost,
+            VERSION,
+            self.variant.type_code(),
+        ] {
+            input.extend_from_slice(&value.to_le_bytes());
+        }
+        for field in [password, salt, &self.secret, &self.associated_data] {
+            input.extend_from_slice(&(field.len() as u32).to_le_bytes());
+            input.extend_from_slice(field);
+        }
+        BLAKE2b::new(512).unwrap().hash(&input).to_slice().to_vec()
+    }
+
+    fn data_independent(&self, position: &Position) -> bool {
+        match self.variant {
+            Argon2Variant::Argon2d => false,
+            Argon2Variant::Argon2i => true,
+            Argon2Variant::Argon2id => position.pass == 0 && position.slice < SYNC_POINTS / 2,
+        }
+    }
+
+    /*
+    Fills one segment of a lane and returns its blocks. Blocks of the
+    segment itself are read from the returned buffer as it grows, every
+    other block is read from the memory as it was at the last
+    synchronization point.
+    */
+    fn fill_segment(&self, memory: &[Block], position: Position) -> Vec<Block> {
+        let lanes: usize = self.lanes as usize;
+        let lane_length: usize = memory.len() / lanes;
+        let segment_length: usize = lane_length / SYNC_POINTS;
+        let lane_start: usize = position.lane * lane_length;
+        let segment_start: usize = position.slice * segment_length;
+
+        let mut segment: Vec<Block> = Vec::with_capacity(segment_length);
+        let read = |segment: &[Block], lane: usize, index: usize| -> Block {
+            if lane == position.lane
+                && (segment_start..segment_start + segment.len()).contains(&index)
+            {
+                segment[index - segment_start]
+            } else {
+                memory[lane * lane_length + index]
+            }
+        };
+
+        let data_independent: bool = self.data_independent(&position);
+        let zero: Block = [0; BLOCK_WORDS];
+        let mut input_block: Block = [0; BLOCK_WORDS];
+        let mut address_block: Block = [0; BLOCK_WORDS];
+        input_block[..6].copy_from_slice(&[
+            position.pass as u64,
+            position.lane as u64,
+            position.slice as u64,
+            memory.len() as u64,
+            self.time_cost as u64,
+            self.variant.type_code() as u64,
+        ]);
+        let next_addresses = |input_block: &mut Block, address_block: &mut Block| {
+            input_block[6] += 1;
+            *address_block = compress(&zero, &compress(&zero, input_block));
+        };
+
+        let starting_index: usize = if position.pass == 0 && position.slice == 0 {
+            segment.push(memory[lane_start]);
+            segment.push(memory[lane_start + 1]);
+            if data_independent {
+                next_addresses(&mut input_block, &mut address_block);
+            }
+            2
+        } else {
+            0
+        };
+
+        for i in starting_index..segment_length {
+            let index: usize = segment_start + i;
+            let previous_index: usize = (index + lane_length - 1) % lane_length;
+            let previous: Block = read(&segment, position.lane, previous_index);
+
+            let pseudo_random: u64 = if data_independent {
+                if i % BLOCK_WORDS == 0 {
+                    next_addresses(&mut input_block, &mut address_block);
+                }
+                address_block[i % BLOCK_WORDS]
+            } else {
+                previous[0]
+            };
+            let j1: u64 = pseudo_random & 0xffffffff;
+            let j2: u64 = pseudo_random >> 32;
+
+            let reference_lane: usize = if position.pass == 0 && position.slice == 0 {
+                position.lane
+            } else {
+                (j2 % lanes as u64) as usize
+            };
+            let same_lane: bool = reference_lane == position.lane;
+            let reference_area: usize = match (position.pass, same_lane) {
+                (0, true) => segment_start + i - 1,
+                (0, false) => segment_start - (i == 0) as usize,
+                (_, true) => lane_length - segment_length + i - 1,
+                (_, false) => lane_length - segment_length - (i == 0) as usize,
+            };
+            let x: u64 = (j1 * j1) >> 32;
+            let y: u64 = (reference_area as u64 * x) >> 32;
+            let relative_position: usize = reference_area - 1 - y as usize;
+            let start_position: usize = match position.pass {
+                0 => 0,
+                _ => ((position.slice + 1) * segment_length) % lane_length,
+            };
+            let reference_index: usize = (start_position + relative_position) % lane_length;
+
+            let reference: Block = read(&segment, reference_lane, reference_index);
+            let mut block: Block = compress(&previous, &reference);
+            if position.pass > 0 {
+                for (b, old) in block.iter_mut().zip(&memory[lane_start + index]) {
+                    *b ^= old;
+                }
+            }
+            segment.push(block);
+        }
+
+        segment
+    }
+
+    pub fn derive(&self, password: &[u8], salt: &[u8], length: usize) -> Result<Digest, KdfError> {
+        let workers: usize = thread::available_parallelism().map_or(1, |n| n.get());
+        self.derive_with_workers(password, salt, length, workers)
+    }
+
+    fn derive_with_workers(
+        &self,
+        password: &[u8],
+        salt: &[u8],
+        length: usize,
+        workers: usize,
+    ) -> Result<Digest, KdfError> {
+        if salt.len() < 8 {
+            return Err(KdfError::InvalidSaltLengthError);
+        }
+        if length < 4 {
+            return Err(KdfError::InvalidOutputLengthError);
+        }
+
+        let lanes: usize = self.lanes as usize;
+        let segment_length: usize = self.memory_cost as usize / (SYNC_POINTS * lanes);
+        let lane_length: usize = segment_length * SYNC_POINTS;
+        let mut memory: Vec<Block> = vec![[0; BLOCK_WORDS]; lane_length * lanes];
+
+        let mut h0: Vec<u8> = self.initial_hash(password, salt, length);
+        for lane in 0..lanes {
+            for i in 0..2 {
+                let mut input: Vec<u8> = h0.clone();
+                input.extend_from_slice(&(i as u32).to_le_bytes());
+                input.extend_from_slice(&(lane as u32).to_le_bytes());
+                let mut block: Vec<u8> = h_prime(&input, 1024);
+                memory[lane * lane_length + i] = block_from_bytes(&block);
+                secret::zeroize(&mut input);
+                secret::zeroize(&mut block);
+            }
+        }
+        secret::zeroize(&mut h0);
+
+        let workers: usize = workers.clamp(1, lanes);
+        for pass in 0..self.time_cost as usize {
+            for slice in 0..SYNC_POINTS {
+                let segments: Vec<Vec<Block>> = match self.fill_slice(&memory, pass, slice, workers)
+                {
+                    Ok(segments) => segments,
+                    Err(error) => {
+                        secret::zeroize_slice(memory.as_flattened_mut());
+                        return Err(error);
+                    }
+                };
+                for (lane, mut segment) in segments.into_iter().enumerate() {
+                    let start: usize = lane * lane_length + slice * segment_length;
+                    memory[start..start + segment_length].copy_from_slice(&segment);
+                    secret::zeroize_slice(segment.as_flattened_mut());
+                }
+            }
+        }
+
+        let mut c: Block = memory[lane_length - 1];
+        for lane in 1..lanes {
+            for (c, b) in c
+                .iter_mut()
+                .zip(&memory[lane * lane_length + lane_length - 1])
+            {
+                *c ^= b;
+            }
+        }
+        secret::zeroize_slice(memory.as_flattened_mut());
+        let mut c_bytes: Vec<u8> = c.iter().flat_map(|word| word.to_le_bytes()).collect();
+        secret::zeroize_slice(&mut c);
+        let tag: Vec<u8> = h_prime(&c_bytes, length);
+        secret::zeroize(&mut c_bytes);
+        Ok(Digest::from_u8(&tag))
+    }
+
+    /*
+    Fills the segments of every lane for one slice. The lanes are
+    independent within a slice, so they are shared out over at most
+    `workers` threads instead of one thread per lane, which keeps a large
+    lane count read from a stored hash from exhausting the system.
+    */
+    fn fill_slice(
+        &self,
+        memory: &[Block],
+        pass: usize,
+        slice: usize,
+        workers: usize,
+    ) -> Result<Vec<Vec<Block>>, KdfError> {
+        let lanes: usize = self.lanes as usize;
+        let fill_lanes = |worker: usize| -> Vec<(usize, Vec<Block>)> {
+            (worker..lanes)
+                .step_by(workers)
+                .map(|lane| {
+                    (
+                        lane,
+                        self.fill_segment(memory, Position { pass, lane, slice }),
+                    )
+                })
+                .collect()
+        };
+        if workers == 1 {
+            return Ok(fill_lanes(0)
+                .into_iter()
+                .map(|(_, segment)| segment)
+                .collect());
+        }
+
+        thread::scope(|scope| {
+            let fill_lanes = &fill_lanes;
+            let handles: Vec<_> = (0..workers)
+                .map(|worker| {
+                    thread::Builder::new().spawn_scoped(scope, move || fill_lanes(worker))
+                })
+                .collect::<Result<_, _>>()
+                .map_err(|_| KdfError::WorkerThreadError)?;
+
+            let mut segments: Vec<Vec<Block>> = vec![vec![]; lanes];
+            for handle in handles {
+                let filled = handle.join().map_err(|_| KdfError::WorkerThreadError)?;
+                for (lane, segment) in filled {
+                    segments[lane] = segment;
+                }
+            }
+            Ok(segments)
+        })
+    }
+
+    /*
+    Encodes the parameters, the salt and the derived hash in the PHC
+    string format, e.g. $argon2id$v=19$m=65536,t=2,p=1$<salt>$<hash>,
+    with unpadded standard base64.
+    */
+    pub fn hash_encoded(
+        &self,
+        password: &[u8],
+        salt: &[u8],
+        length: usize,
+    ) -> Result<String, KdfError> {
+        let hash: Digest = self.derive(password, salt, length)?;
+        let phc: PhcString = PhcString::new(self.variant.name())
+            .and_then(|phc| {
+                phc.with_version(VERSION)
+                    .with_param("m", &self.memory_cost.to_string())
+            })
+            .and_then(|phc| phc.with_param("t", &self.time_cost.to_string()))
+            .and_then(|phc| phc.with_param("p", &self.lanes.to_string()))
+            .map_err(|_| KdfError::InvalidEncodingError)?;
+        Ok(phc.with_salt(salt).with_hash(hash.to_slice()).to_string())
+    }
+
+    /* Verifies with the default cost limits, see verify_encoded_with_limits. */
+    pub fn verify_encoded(encoded: &str, password: &[u8]) -> Result<bool, KdfError> {
+        Self::verify_encoded_with_limits(encoded, password, &CostLimits::new())
+    }
+
+    /*
+    Verifies a password against a PHC string, rejecting with
+    InvalidCostError a string whose memory, time cost or lanes exceed the
+    limits before any memory is allocated.
+    */
+    pub fn verify_encoded_with_limits(
+        encoded: &str,
+        password: &[u8],
+        limits: &CostLimits,
+    ) -> Result<bool, KdfError> {
+        let phc: PhcString =
+            PhcString::parse(encoded).map_err(|_| KdfError::InvalidEncodingError)?;
+        let variant: Argon2Variant = match phc.id() {
+            "argon2d" => Argon2Variant::Argon2d,
+            "argon2i" => Argon2Variant::Argon2i,
+            "argon2id" => Argon2Variant::Argon2id,
+            _ => return Err(KdfError::InvalidEncodingError),
+        };
+        if phc.version() != Some(VERSION) || phc.params().len() != 3 {
+            return Err(KdfError::InvalidEncodingError);
+        }
+        let param = |name: &str| -> Result<u32, KdfError> {
+            phc.param(name)
+                .and_then(|value| value.parse().ok())
+                .ok_or(KdfError::InvalidEncodingError)
+        };
+        let (Some(salt), Some(hash)) = (phc.salt(), phc.hash()) else {
+            return Err(KdfError::InvalidEncodingError);
+        };
+
+        let (m, t, p): (u32, u32, u32) = (param("m")?, param("t")?, param("p")?);
+        limits.check(m as u64 * 1024, t as u64, p as u64)?;
+
+        let argon2: Argon2 = Argon2::new(variant, m, t, p)?;
+        Ok(argon2.derive(password, salt, hash.len())?.verify(hash))
+    }
+}
+
+impl Drop for Argon2 {
+    fn drop(&mut self) {
+        secret::zeroize(&mut self.secret);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rfc9106(variant: Argon2Variant) -> String {
+        Argon2::new(variant, 32, 3, 4)
+            .unwrap()
+            .with_secret(&[0x03; 8])
+            .with_associated_data(&[0x04; 12])
+            .derive(&[0x01; 32], &[0x02; 16], 32)
+            .unwrap()
+            .to_hex()
+    }
+
+    #[test]
+    fn test_argon2_on_rfc9106_suite() {
+        assert_eq!(
+            rfc9106(Argon2Variant::Argon2d),
+            "512b391b6f1162975371d30919734294f868e3be3984f3c1a13a4db9fabe4acb"
+        );
+        assert_eq!(
+            rfc9106(Argon2Variant::Argon2i),
+            "c814d9d1dc7f37aa13f0d77f2494bda1c8de6b016dd388d29952a4c4672b6ce8"
+        );
+        assert_eq!(
+            rfc9106(Argon2Variant::Argon2id),
+            "0d640df58d78766c08c037a34a8b53c9d01ef0452d75b65eb52520e96b01e659"
+        );
+    }
+
+    #[test]
+    fn test_argon2_multiple_passes_and_lanes() {
+        let argon2 = Argon2::new(Argon2Variant::Argon2i, 4096, 3, 2).unwrap();
+        assert_eq!(
+            argon2.derive(b"password", b"saltsaltsalt", 64).unwrap().to_hex(),
+            "d30e5b137fe3fdd0b4ae9a969e73e64dcef6316c00b7b065fc731b8ca863491014a955bfeab5a086cc6a47a70c7554f33c0c793fda196c662bfeed2c52838bd1"
+        );
+        let argon2 = Argon2::new(Argon2Variant::Argon2d, 1031, 1, 3).unwrap();
+        assert_eq!(
+            argon2.derive(b"hunter2", b"NaClNaCl", 100).unwrap().to_hex(),
+            "2f17aafba9d9d9c6b48fbbba2e3e6543f6c9916fcd390388bdbcfeabf8eb7e6d72e6644a14fb2614dbc742217535018dff456d499a00d36dba16943eb9828d950f5e2b641581f07d8a38f0875304b20f7c159b2e18caf190f429286a76d10d1e25d99195"
+        );
+    }
+
+    #[test]
+    fn test_argon2_phc_string() {
+        let argon2 = Argon2::new(Argon2Variant::Argon2i, 4096, 3, 2).unwrap();
+        let encoded: String = argon2
+            .hash_encoded(b"password", b"saltsaltsalt", 64)
+            .unwrap();
+        assert_eq!(
+            encoded,
+            "$argon2i$v=19$m=4096,t=3,p=2$c2FsdHNhbHRzYWx0$0w5bE3/j/dC0rpqWnnPmTc72MWwAt7Bl/HMbjKhjSRAUqVW/6rWghsxqR6cMdVTzPAx5P9oZbGYr/u0sUoOL0Q"
+        );
+        assert!(Argon2::verify_encoded(&encoded, b"password").unwrap());
+        assert!(!Argon2::verify_encoded(&encoded, b"Password").unwrap());
+
+        let encoded = "$argon2d$v=19$m=1031,t=1,p=3$TmFDbE5hQ2w$Lxeq+6nZ2ca0j7u6Lj5lQ/bJkW/NOQOIvbz+q/jrfm1y5mRKFPsmFNvHQiF1NQGN/0VtSZoA0226FpQ+uYKNlQ9eK2QVgfB9ijjwh1MEsg98FZsuGMrxkPQpKGp20Q0eJdmRlQ";
+        assert!(Argon2::verify_encoded(encoded, b"hunter2").unwrap());
+
+        for invalid in [
+            "$argon2x$v=19$m=4096,t=3,p=2$c2FsdHNhbHRzYWx0$0w5bE3",
+            "$argon2i$v=16$m=4096,t=3,p=2$c2FsdHNhbHRzYWx0$0w5bE3",
+            "$argon2i$v=19$m=4096,t=3$c2FsdHNhbHRzYWx0$0w5bE3",
+            "$argon2i$v=19$m=4096,t=3,p=2,p=2$c2FsdHNhbHRzYWx0$0w5bE3",
+            "$argon2i$v=19$m=4096,t=3,p=2$c2FsdHNhbHRzYWx0$0w5bE",
+            "$argon2i$v=19$m=4096,t=3,p=2$c2FsdHNhbHRzYWx0",
+        ] {
+            assert!(Argon2::verify_encoded(invalid, b"password").is_err());
+        }
+    }
+
+    #[test]
+    fn test_argon2_verify_cost_limits() {
+        let hash = "c2FsdHNhbHRzYWx0$0w5bE3/j/dC0rpqWnnPmTc72MWwAt7Bl/HMbjKhjSRAUqVW/6rWghsxqR6cMdVTzPAx5P9oZbGYr/u0sUoOL0Q";
+        for params in ["m=4294967295,t=3,p=2", "m=4096,t=17,p=2", "m=4096,t=3,p=65"] {
+            assert!(matches!(
+                Argon2::verify_encoded(&format!("$argon2i$v=19${params}${hash}"), b"password"),
+                Err(KdfError::InvalidCostError)
+            ));
+        }
+
+        let encoded: String = format!("$argon2i$v=19$m=4096,t=3,p=2${hash}");
+        let limits = CostLimits::new().with_max_memory(4095 * 1024);
+        assert!(matches!(
+            Argon2::verify_encoded_with_limits(&encoded, b"password", &limits),
+            Err(KdfError::InvalidCostError)
+        ));
+        let limits = limits
+            .with_max_memory(4096 * 1024)
+            .with_max_passes(3)
+            .with_max_lanes(2);
+        assert!(Argon2::verify_encoded_with_limits(&encoded, b"password", &limits).unwrap());
+    }
+
+    #[test]
+    fn test_argon2_invalid_parameters() {
+        assert!(Argon2::new(Argon2Variant::Argon2id, 8, 1, 0).is_err());
+        assert!(Argon2::new(Argon2Variant::Argon2id, 8, 0, 1).is_err());
+        assert!(Argon2::new(Argon2Variant::Argon2id, 15, 1, 2).is_err());
+        let argon2 = Argon2::new(Argon2Variant::Argon2id, 8, 1, 1).unwrap();
+        assert!(argon2.derive(b"password", b"short", 32).is_err());
+        assert!(argon2.derive(b"password", b"somesalt", 3).is_err());
+    }
+
+    #[test]
+    fn test_argon2_many_lanes_on_bounded_workers() {
+        let tests = [
+            (
+                Argon2Variant::Argon2id,
+                4096,
+                1,
+                64,
+                &b"somesalt"[..],
+                "ba1b62f9cbf3bb1fd8d1a044ff72aee3888988308a58be598e21f9b72e97b77b",
+            ),
+            (
+                Argon2Variant::Argon2i,
+                2048,
+                2,
+                37,
+                b"saltsaltsalt",
+                "db711cf11d0ece473f03d5d758ff462c10166f760d2de8bdfd815519d2190ca8",
+            ),
+        ];
+
+        for (variant, memory_cost, time_cost, lanes, salt, expected) in tests {
+            let argon2 = Argon2::new(variant, memory_cost, time_cost, lanes).unwrap();
+            for workers in [0, 1, 3, 8, 1000] {
+                let tag = argon2
+                    .derive_with_workers(b"password", salt, 32, workers)
+                    .unwrap();
+                assert_eq!(tag.to_hex(), expected);
+            }
+        }
+    }
+}
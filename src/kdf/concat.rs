@@ -0,0 +1,73 @@
+use crate::hash::{Digest, HashFunction, Message};
+use crate::kdf::KdfError;
+
+/*
+The hash-based single-step key derivation function of NIST SP 800-56C,
+also known as the Concat KDF. It hashes a 32-bit big-endian counter
+starting at one, followed by the shared secret and the other info.
+*/
+pub struct ConcatKDF<H: HashFunction> {
+    hasher: H,
+}
+
+impl<H: HashFunction> ConcatKDF<H> {
+    pub fn new(hasher: H) -> Self {
+        Self { hasher }
+    }
+
+    pub fn derive(
+        &self,
+        shared_secret: &[u8],
+        other_info: &[u8],
+        length: usize,
+    ) -> Result<Digest, KdfError> {
+        let hash_length: usize = self.hasher.hash(&Message::new()).to_slice().len();
+        if length == 0 || length.div_ceil(hash_length) as u64 > u32::MAX as u64 {
+            return Err(KdfError::InvalidOutputLengthError);
+        }
+
+        let mut output: Vec<u8> = Vec::with_capacity(length + hash_length);
+        let mut counter: u32 = 1;
+        while output.len() < length {
+            let mut input: Message = Message::from_slice(&counter.to_be_bytes());
+            input.extend_from_slice(shared_secret);
+            input.extend_from_slice(other_info);
+            output.extend_from_slice(self.hasher.hash(&input).to_slice());
+            counter += 1;
+        }
+        output.truncate(length);
+        Ok(Digest::from_u8(&output))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hash::sha256::SHA256;
+    use crate::hash::sha512::SHA512;
+
+    #[test]
+    fn test_concat_kdf() {
+        let z =
+            Message::from_hex("52169af5c485dcc2321eb8d26d5efa21fb9b93c98e38412ee2484cf14f0d0d23")
+                .unwrap();
+        let other_info = Message::from_hex("a1b2c3d4e5").unwrap();
+        assert_eq!(
+            ConcatKDF::new(SHA256)
+                .derive(z.to_slice(), other_info.to_slice(), 16)
+                .unwrap()
+                .to_hex(),
+            "40ca4cd1665a03e9083c2c91141fa3a8"
+        );
+
+        let z = Message::from_hex("96c05619d56c328ab95fe84b18264b08725b85e33fd34f08").unwrap();
+        assert_eq!(
+            ConcatKDF::new(SHA512)
+                .derive(z.to_slice(), b"other", 100)
+                .unwrap()
+                .to_hex(),
+            "bd8edea79afb80c22ab1f620d4dfcced00f7a97c9e4d5557e2a9981682fab2cc79791ae89daa16e13282059df84c058f77411225f88c6d8eaccaeef45ae66fc95b5f3aed9d4fa2bbf1fca81b2b76fbfdab7d2dd4c01fc854b904e4bb814ca8293f1c9a8a"
+        );
+        assert!(ConcatKDF::new(SHA256).derive(&[], &[], 0).is_err());
+    }
+}
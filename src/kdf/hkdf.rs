@@ -0,0 +1,182 @@
+use crate::hash::{Digest, HashFunction, Message};
+use crate::kdf::KdfError;
+use crate::mac::hmac::HMAC;
+
+/*
+HKDF as specified in RFC 5869, built on HMAC with any hash function of
+the crate. Extract concentrates the input keying material into a
+pseudorandom key of one hash length, expand stretches a pseudorandom key
+to at most 255 hash lengths of output bound to the info string.
+*/
+pub struct HKDF<H: HashFunction> {
+    hasher: H,
+    hash_length: usize,
+}
+
+impl<H: HashFunction> HKDF<H> {
+    pub fn new(hasher: H) -> Self {
+        let hash_length: usize = hasher.hash(&Message::new()).to_slice().len();
+        Self {
+            hasher,
+            hash_length,
+        }
+    }
+
+    pub fn extract(&self, salt: &[u8], ikm: &[u8]) -> Digest {
+        let salt: Vec<u8> = if salt.is_empty() {
+            vec![0; self.hash_length]
+        } else {
+            salt.to_vec()
+        };
+        HMAC::new(&self.hasher, &salt).mac(&Message::from_slice(ikm))
+    }
+
+    pub fn expand(&self, prk: &[u8], info: &[u8], length: usize) -> Result<Digest, KdfError> {
+        if prk.len() < self.hash_length {
+            return Err(KdfError::InvalidKeyLengthError);
+        }
+        if length > 255 * self.hash_length {
+            return Err(KdfError::InvalidOutputLengthError);
+        }
+
+        let hmac: HMAC<&H> = HMAC::new(&self.hasher, prk);
+        let mut okm: Vec<u8> = Vec::with_capacity(length + self.hash_length);
+        let mut t: Vec<u8> = vec![];
+        let mut counter: u8 = 1;
+        while okm.len() < length {
+            let mut input: Message = Message::from_slice(&t);
+            input.extend_from_slice(info);
+            input.extend_from_slice(&[counter]);
+            t = hmac.mac(&input).to_slice().to_vec();
+            okm.extend_from_slice(&t);
+            counter = counter.wrapping_add(1);
+        }
+        okm.truncate(length);
+        Ok(Digest::from_u8(&okm))
+    }
+
+    pub fn derive(
+        &self,
+        salt: &[u8],
+        ikm: &[u8],
+        info: &[u8],
+        length: usize,
+    ) -> Result<Digest, KdfError> {
+        let prk: Digest = self.extract(salt, ikm);
+        self.expand(prk.to_slice(), info, length)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hash::sha1::SHA1;
+    use crate::hash::sha256::SHA256;
+
+    fn range(start: u8, end: u8) -> Vec<u8> {
+        (start..end).collect()
+    }
+
+    #[test]
+    fn test_hkdf_sha256_on_rfc5869_suite() {
+        let hkdf = HKDF::new(SHA256);
+        let tests = [
+            (
+                range(0x00, 0x0d),
+                vec![0x0b; 22],
+                range(0xf0, 0xfa),
+                42,
+                "077709362c2e32df0ddc3f0dc47bba6390b6c73bb50f9c3122ec844ad7c2b3e5",
+                "3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db02d56ecc4c5bf34007208d5b887185865",
+            ),
+            (
+                range(0x60, 0xb0),
+                range(0x00, 0x50),
+                (0xb0..=0xff).collect(),
+                82,
+                "06a6b88c5853361a06104c9ceb35b45cef760014904671014a193f40c15fc244",
+                "b11e398dc80327a1c8e7f78c596a49344f012eda2d4efad8a050cc4c19afa97c59045a99cac7827271cb41c65e590e09da3275600c2f09b8367793a9aca3db71cc30c58179ec3e87c14c01d5c1f3434f1d87",
+            ),
+            (
+                vec![],
+                vec![0x0b; 22],
+                vec![],
+                42,
+                "19ef24a32c717b167f33a91d6f648bdf96596776afdb6377ac434c1c293ccb04",
+                "8da4e775a563c18f715f802a063c5a31b8a11f5c5ee1879ec3454e5f3c738d2d9d201395faa4b61a96c8",
+            ),
+        ];
+
+        for (salt, ikm, info, length, prk, okm) in tests {
+            let computed_prk: Digest = hkdf.extract(&salt, &ikm);
+            assert_eq!(computed_prk.to_hex(), prk);
+            assert_eq!(
+                hkdf.expand(computed_prk.to_slice(), &info, length)
+                    .unwrap()
+                    .to_hex(),
+                okm
+            );
+            assert_eq!(
+                hkdf.derive(&salt, &ikm, &info, length).unwrap().to_hex(),
+                okm
+            );
+        }
+    }
+
+    #[test]
+    fn test_hkdf_sha1_on_rfc5869_suite() {
+        let hkdf = HKDF::new(SHA1);
+        let tests = [
+            (
+                range(0x00, 0x0d),
+                vec![0x0b; 11],
+                range(0xf0, 0xfa),
+                42,
+                "9b6c18c432a7bf8f0e71c8eb88f4b30baa2ba243",
+                "085a01ea1b10f36933068b56efa5ad81a4f14b822f5b091568a9cdd4f155fda2c22e422478d305f3f896",
+            ),
+            (
+                range(0x60, 0xb0),
+                range(0x00, 0x50),
+                (0xb0..=0xff).collect(),
+                82,
+                "8adae09a2a307059478d309b26c4115a224cfaf6",
+                "0bd770a74d1160f7c9f12cd5912a06ebff6adcae899d92191fe4305673ba2ffe8fa3f1a4e5ad79f3f334b3b202b2173c486ea37ce3d397ed034c7f9dfeb15c5e927336d0441f4c4300e2cff0d0900b52d3b4",
+            ),
+            (
+                vec![],
+                vec![0x0b; 22],
+                vec![],
+                42,
+                "da8c8a73c7fa77288ec6f5e7c297786aa0d32d01",
+                "0ac1af7002b3d761d1e55298da9d0506b9ae52057220a306e07b6b87e8df21d0ea00033de03984d34918",
+            ),
+            (
+                vec![],
+                vec![0x0c; 22],
+                vec![],
+                42,
+                "2adccada18779e7c2077ad2eb19d3f3e731385dd",
+                "2c91117204d745f3500d636a62f64f0ab3bae548aa53d423b0d1f27ebba6f5e5673a081d70cce7acfc48",
+            ),
+        ];
+
+        for (salt, ikm, info, length, prk, okm) in tests {
+            let computed_prk: Digest = hkdf.extract(&salt, &ikm);
+            assert_eq!(computed_prk.to_hex(), prk);
+            assert_eq!(
+                hkdf.derive(&salt, &ikm, &info, length).unwrap().to_hex(),
+                okm
+            );
+        }
+    }
+
+    #[test]
+    fn test_hkdf_invalid_parameters() {
+        let hkdf = HKDF::new(SHA256);
+        let prk: Digest = hkdf.extract(&[], &[0x0b; 22]);
+        assert!(hkdf.expand(prk.to_slice(), &[], 255 * 32).is_ok());
+        assert!(hkdf.expand(prk.to_slice(), &[], 255 * 32 + 1).is_err());
+        assert!(hkdf.expand(&prk.to_slice()[..31], &[], 32).is_err());
+    }
+}
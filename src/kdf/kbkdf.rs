@@ -0,0 +1,191 @@
+use crate::hash::{Digest, Message};
+use crate::kdf::KdfError;
+use crate::mac::MacFunction;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum KbkdfMode {
+    Counter,
+    Feedback,
+    DoublePipeline,
+}
+
+/*
+Key-based key derivation functions from NIST SP 800-108, in counter,
+feedback and double-pipeline modes, over any keyed MAC of the crate
+used as the pseudorandom function, such as HMAC or CMAC. The counter is
+written big-endian before the fixed input data and may be left out in
+the feedback and double-pipeline modes. The fixed input data is either
+given as is, or built from a label and a context as
+Label || 0x00 || Context || [L]32, with L the output length in bits.
+*/
+pub struct KBKDF<M: MacFunction> {
+    prf: M,
+    mode: KbkdfMode,
+    counter_length: usize,
+    iv: Vec<u8>,
+}
+
+impl<M: MacFunction> KBKDF<M> {
+    pub fn new(prf: M, mode: KbkdfMode) -> Self {
+        Self {
+            prf,
+            mode,
+            counter_length: 4,
+            iv: vec![],
+        }
+    }
+
+    /* Length of the counter in bits, zero to leave it out. */
+    pub fn with_counter_length(mut self, bits: usize) -> Result<Self, KdfError> {
+        if !matches!(bits, 0 | 8 | 16 | 24 | 32) || (bits == 0 && self.mode == KbkdfMode::Counter) {
+            return Err(KdfError::InvalidCounterLengthError);
+        }
+        self.counter_length = bits / 8;
+        Ok(self)
+    }
+
+    /* Initial value K(0) of the feedback mode, empty by default. */
+    pub fn with_iv(mut self, iv: &[u8]) -> Self {
+        self.iv = iv.to_vec();
+        self
+    }
+
+    fn counter(&self, i: u32) -> Vec<u8> {
+        i.to_be_bytes()[4 - self.counter_length..].to_vec()
+    }
+
+    pub fn derive(&self, label: &[u8], context: &[u8], length: usize) -> Result<Digest, KdfError> {
+        let mut fixed_input: Vec<u8> = label.to_vec();
+        fixed_input.push(0x00);
+        fixed_input.extend_from_slice(context);
+        fixed_input.extend_from_slice(&((length as u64 * 8) as u32).to_be_bytes());
+        self.derive_from_fixed_input(&fixed_input, length)
+    }
+
+    pub fn derive_from_fixed_input(
+        &self,
+        fixed_input: &[u8],
+        length: usize,
+    ) -> Result<Digest, KdfError> {
+        let hash_length: usize = self.prf.mac(&Message::new()).to_slice().len();
+        let blocks: u64 = length.div_ceil(hash_length) as u64;
+        let max_blocks: u64 = match self.counter_length {
+            0 => u32::MAX as u64,
+            counter_length => (1 << (8 * counter_length)) - 1,
+        };
+        if length == 0 || length as u64 * 8 > u32::MAX as u64 || blocks > max_blocks {
+            return Err(KdfError::InvalidOutputLengthError);
+        }
+
+        let mut output: Vec<u8> = Vec::with_capacity(blocks as usize * hash_length);
+        let mut k: Vec<u8> = self.iv.clone();
+        let mut a: Vec<u8> = fixed_input.to_vec();
+        for i in 1..=blocks as u32 {
+            let mut input: Message = Message::new();
+            match self.mode {
+                KbkdfMode::Counter => {}
+                KbkdfMode::Feedback => input.extend_from_slice(&k),
+                KbkdfMode::DoublePipeline => {
+                    a = self.prf.mac(&Message::from_slice(&a)).to_slice().to_vec();
+                    input.extend_from_slice(&a);
+                }
+            }
+            input.extend_from_slice(&self.counter(i));
+            input.extend_from_slice(fixed_input);
+            k = self.prf.mac(&input).to_slice().to_vec();
+            output.extend_from_slice(&k);
+        }
+        output.truncate(length);
+        Ok(Digest::from_u8(&output))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cipher::aes::AES;
+    use crate::hash::sha256::SHA256;
+    use crate::mac::cmac::CMAC;
+    use crate::mac::hmac::HMAC;
+
+    fn hmac() -> HMAC<SHA256> {
+        HMAC::new(SHA256, &(0..32).collect::<Vec<u8>>())
+    }
+
+    fn cmac() -> CMAC<AES> {
+        CMAC::new(AES::new(&(0..16).collect::<Vec<u8>>()).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_kbkdf_counter_mode() {
+        let kbkdf = KBKDF::new(hmac(), KbkdfMode::Counter);
+        assert_eq!(
+            kbkdf.derive(b"label", b"context", 32).unwrap().to_hex(),
+            "303790cfe363abe9682dbfff5941f23b32addc96da72f4c7e5b20e9f59a4e570"
+        );
+        assert_eq!(
+            kbkdf.derive(b"label", b"context", 42).unwrap().to_hex(),
+            "b9cd5f6323f01f4680650855f1ebea9b4c54c08131b506fc28c856364a38a2f4fb680c12ea51696887d9"
+        );
+
+        let kbkdf = KBKDF::new(cmac(), KbkdfMode::Counter)
+            .with_counter_length(8)
+            .unwrap();
+        assert_eq!(
+            kbkdf.derive(b"label", b"context", 40).unwrap().to_hex(),
+            "a4e6996b4aa19a4a371d268ef97514aff8709856736740c998231016e03d852c3b5d8b772f6ad582"
+        );
+    }
+
+    #[test]
+    fn test_kbkdf_feedback_mode() {
+        let iv: Vec<u8> = (0xa0..0xc0).collect();
+        let kbkdf = KBKDF::new(hmac(), KbkdfMode::Feedback).with_iv(&iv);
+        assert_eq!(
+            kbkdf.derive(b"label", b"context", 42).unwrap().to_hex(),
+            "07cac9444555539403f2a1bae9661320b0830b9f6fa81978ab02720266e9b24f4b422dc40f6312efb682"
+        );
+
+        let kbkdf = KBKDF::new(hmac(), KbkdfMode::Feedback)
+            .with_counter_length(0)
+            .unwrap();
+        assert_eq!(
+            kbkdf.derive(b"label", b"context", 42).unwrap().to_hex(),
+            "7155fabef6d6702a2dcd4322ca723b543b54881fcb62a8d8f180bb76f59968d9f0b9d8adbca711e9fbd0"
+        );
+    }
+
+    #[test]
+    fn test_kbkdf_double_pipeline_mode() {
+        let kbkdf = KBKDF::new(hmac(), KbkdfMode::DoublePipeline);
+        assert_eq!(
+            kbkdf.derive(b"label", b"context", 42).unwrap().to_hex(),
+            "76e610b7ccf1e56845e527cfdcbd74231fe34eee312dd6bc1a9ac8933771f7b7d276a4fd588e72ce030f"
+        );
+
+        let kbkdf = KBKDF::new(cmac(), KbkdfMode::DoublePipeline)
+            .with_counter_length(0)
+            .unwrap();
+        assert_eq!(
+            kbkdf.derive(b"label", b"context", 40).unwrap().to_hex(),
+            "fe074d6521bbd5879122939c3cd954fa15ad835812cf0f92d3200a5d6e58da28dd9b3128dbe6408a"
+        );
+    }
+
+    #[test]
+    fn test_kbkdf_invalid_parameters() {
+        assert!(KBKDF::new(hmac(), KbkdfMode::Counter)
+            .with_counter_length(0)
+            .is_err());
+        assert!(KBKDF::new(hmac(), KbkdfMode::Feedback)
+            .with_counter_length(12)
+            .is_err());
+
+        let kbkdf = KBKDF::new(cmac(), KbkdfMode::Counter)
+            .with_counter_length(8)
+            .unwrap();
+        assert!(kbkdf.derive(b"", b"", 0).is_err());
+        assert!(kbkdf.derive(b"", b"", 255 * 16).is_ok());
+        assert!(kbkdf.derive(b"", b"", 255 * 16 + 1).is_err());
+    }
+}
@@ -0,0 +1,165 @@
+use crate::hash::{Digest, HashFunction, Message};
+use crate::kdf::KdfError;
+use crate::mac::hmac::HMAC;
+
+/*
+PBKDF2 as specified in RFC 8018, with HMAC over any hash function of the
+crate as the pseudorandom function. Each block of output is the XOR of
+the chained HMAC values of one counter block, so the output can be of any
+length up to (2^32 - 1) hash lengths.
+*/
+pub struct PBKDF2<H: HashFunction> {
+    hasher: H,
+    iterations: u32,
+}
+
+impl<H: HashFunction> PBKDF2<H> {
+    pub fn new(hasher: H, iterations: u32) -> Result<Self, KdfError> {
+        if iterations == 0 {
+            return Err(KdfError::InvalidIterationCountError);
+        }
+        Ok(Self { hasher, iterations })
+    }
+
+    pub fn derive(&self, password: &[u8], salt: &[u8], length: usize) -> Result<Digest, KdfError> {
+        let hmac: HMAC<&H> = HMAC::new(&self.hasher, password);
+        let hash_length: usize = hmac.mac(&Message::new()).to_slice().len();
+        if length == 0 || length as u64 > u32::MAX as u64 * hash_length as u64 {
+            return Err(KdfError::InvalidOutputLengthError);
+        }
+
+        let mut output: Vec<u8> = Vec::with_capacity(length + hash_length);
+        let mut counter: u32 = 1;
+        while output.len() < length {
+            let mut input: Message = Message::from_slice(salt);
+            input.extend_from_slice(&counter.to_be_bytes());
+            let mut u: Digest = hmac.mac(&input);
+            let mut block: Vec<u8> = u.to_slice().to_vec();
+            for _ in 1..self.iterations {
+                u = hmac.mac(&Message::from_slice(u.to_slice()));
+                for (b, x) in block.iter_mut().zip(u.to_slice()) {
+                    *b ^= x;
+                }
+            }
+            output.extend_from_slice(&block);
+            counter += 1;
+        }
+        output.truncate(length);
+        Ok(Digest::from_u8(&output))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hash::sha1::SHA1;
+    use crate::hash::sha256::SHA256;
+    use crate::hash::sha3::SHA3_256;
+    use crate::hash::sha512::SHA512;
+
+    const LONG_PASSWORD: &str = "passwordPASSWORDpassword";
+    const LONG_SALT: &str = "saltSALTsaltSALTsaltSALTsaltSALTsalt";
+
+    #[test]
+    fn test_pbkdf2_sha1_on_rfc6070_suite() {
+        let tests = [
+            (
+                "password",
+                "salt",
+                1,
+                20,
+                "0c60c80f961f0e71f3a9b524af6012062fe037a6",
+            ),
+            (
+                "password",
+                "salt",
+                2,
+                20,
+                "ea6c014dc72d6f8ccd1ed92ace1d41f0d8de8957",
+            ),
+            (
+                "password",
+                "salt",
+                4096,
+                20,
+                "4b007901b765489abead49d926f721d065a429c1",
+            ),
+            (
+                LONG_PASSWORD,
+                LONG_SALT,
+                4096,
+                25,
+                "3d2eec4fe41c849b80c8d83662c0e44a8b291a964cf2f07038",
+            ),
+            (
+                "pass\0word",
+                "sa\0lt",
+                4096,
+                16,
+                "56fa6aa75548099dcc37d7f03425e0c3",
+            ),
+        ];
+
+        for (password, salt, iterations, length, expected) in tests {
+            let pbkdf2 = PBKDF2::new(SHA1, iterations).unwrap();
+            assert_eq!(
+                pbkdf2
+                    .derive(password.as_bytes(), salt.as_bytes(), length)
+                    .unwrap()
+                    .to_hex(),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_pbkdf2_sha1_on_wpa2_psk() {
+        let pbkdf2 = PBKDF2::new(SHA1, 4096).unwrap();
+        assert_eq!(
+            pbkdf2.derive(b"password", b"IEEE", 32).unwrap().to_hex(),
+            "f42c6fc52df0ebef9ebb4b90b38a5f902e83fe1b135a70e23aed762e9710a12e"
+        );
+    }
+
+    #[test]
+    fn test_pbkdf2_sha256_on_rfc7914_suite() {
+        let pbkdf2 = PBKDF2::new(SHA256, 1).unwrap();
+        assert_eq!(
+            pbkdf2.derive(b"passwd", b"salt", 64).unwrap().to_hex(),
+            "55ac046e56e3089fec1691c22544b605f94185216dde0465e68b9d57c20dacbc49ca9cccf179b645991664b39d77ef317c71b845b1e30bd509112041d3a19783"
+        );
+        let pbkdf2 = PBKDF2::new(SHA256, 80000).unwrap();
+        assert_eq!(
+            pbkdf2.derive(b"Password", b"NaCl", 64).unwrap().to_hex(),
+            "4ddcd8f60b98be21830cee5ef22701f9641a4418d04c0414aeff08876b34ab56a1d425a1225833549adb841b51c9b3176a272bdebba1d078478f62b397f33c8d"
+        );
+    }
+
+    #[test]
+    fn test_pbkdf2_sha512_and_sha3() {
+        let pbkdf2 = PBKDF2::new(SHA512, 1).unwrap();
+        assert_eq!(
+            pbkdf2.derive(b"password", b"salt", 64).unwrap().to_hex(),
+            "867f70cf1ade02cff3752599a3a53dc4af34c7a669815ae5d513554e1c8cf252c02d470a285a0501bad999bfe943c08f050235d7d68b1da55e63f73b60a57fce"
+        );
+        let pbkdf2 = PBKDF2::new(SHA512, 4096).unwrap();
+        assert_eq!(
+            pbkdf2.derive(LONG_PASSWORD.as_bytes(), LONG_SALT.as_bytes(), 64).unwrap().to_hex(),
+            "8c0511f4c6e597c6ac6315d8f0362e225f3c501495ba23b868c005174dc4ee71115b59f9e60cd9532fa33e0f75aefe30225c583a186cd82bd4daea9724a3d3b8"
+        );
+        let pbkdf2 = PBKDF2::new(SHA3_256::new(), 1000).unwrap();
+        assert_eq!(
+            pbkdf2.derive(b"password", b"salt", 32).unwrap().to_hex(),
+            "ee56a9b7311bb081d0bbfa8dc3c2798f30abbbec6344426829d956ed06eaecab"
+        );
+    }
+
+    #[test]
+    fn test_pbkdf2_invalid_parameters() {
+        assert!(PBKDF2::new(SHA256, 0).is_err());
+        assert!(PBKDF2::new(SHA256, 1)
+            .unwrap()
+            .derive(b"password", b"salt", 0)
+            .is_err());
+    }
+}
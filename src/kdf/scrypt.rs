@@ -0,0 +1,172 @@
+use crate::hash::sha256::SHA256;
+use crate::hash::Digest;
+use crate::kdf::pbkdf2::PBKDF2;
+use crate::kdf::KdfError;
+
+fn salsa20_8(b: &mut [u32; 16]) {
+    let mut x: [u32; 16] = *b;
+    let quarter_round = |x: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize| {
+        x[b] ^= x[a].wrapping_add(x[d]).rotate_left(7);
+        x[c] ^= x[b].wrapping_add(x[a]).rotate_left(9);
+        x[d] ^= x[c].wrapping_add(x[b]).rotate_left(13);
+        x[a] ^= x[d].wrapping_add(x[c]).rotate_left(18);
+    };
+    for _ in 0..4 {
+        quarter_round(&mut x, 0, 4, 8, 12);
+        quarter_round(&mut x, 5, 9, 13, 1);
+        quarter_round(&mut x, 10, 14, 2, 6);
+        quarter_round(&mut x, 15, 3, 7, 11);
+        quarter_round(&mut x, 0, 1, 2, 3);
+        quarter_round(&mut x, 5, 6, 7, 4);
+        quarter_round(&mut x, 10, 11, 8, 9);
+        quarter_round(&mut x, 15, 12, 13, 14);
+    }
+    for (b, x) in b.iter_mut().zip(&x) {
+        *b = b.wrapping_add(*x);
+    }
+}
+
+/*
+BlockMix over 2r blocks of 16 words. The even outputs are stored in the
+first half of the result and the odd outputs in the second half.
+*/
+fn block_mix(input: &[u32], output: &mut [u32], r: usize) {
+    let mut x: [u32; 16] = input[(2 * r - 1) * 16..].try_into().unwrap();
+    for (i, block) in input.chunks_exact(16).enumerate() {
+        for (x, b) in x.iter_mut().zip(block) {
+            *x ^= b;
+        }
+        salsa20_8(&mut x);
+        let offset: usize = (i / 2 + (i % 2) * r) * 16;
+        output[offset..offset + 16].copy_from_slice(&x);
+    }
+}
+
+fn integerify(x: &[u32], r: usize) -> u64 {
+    let offset: usize = (2 * r - 1) * 16;
+    x[offset] as u64 | (x[offset + 1] as u64) << 32
+}
+
+fn ro_mix(b: &mut [u8], n: usize, r: usize) {
+    let words: usize = 32 * r;
+    let mut x: Vec<u32> = b
+        .chunks_exact(4)
+        .map(|chunk| u32::from_le_bytes(chunk.try_into().unwrap()))
+        .collect();
+    let mut y: Vec<u32> = vec![0; words];
+    let mut v: Vec<u32> = Vec::with_capacity(n * words);
+
+    for _ in 0..n {
+        v.extend_from_slice(&x);
+        block_mix(&x, &mut y, r);
+        std::mem::swap(&mut x, &mut y);
+    }
+    for _ in 0..n {
+        let j: usize = (integerify(&x, r) & (n as u64 - 1)) as usize;
+        for (x, v) in x.iter_mut().zip(&v[j * words..(j + 1) * words]) {
+            *x ^= v;
+        }
+        block_mix(&x, &mut y, r);
+        std::mem::swap(&mut x, &mut y);
+    }
+
+    for (chunk, x) in b.chunks_exact_mut(4).zip(&x) {
+        chunk.copy_from_slice(&x.to_le_bytes());
+    }
+}
+
+/*
+scrypt as specified in RFC 7914. N is the CPU/memory cost and must be a
+power of two greater than one, r is the block size and p the
+parallelization parameter. ROMix keeps N blocks of 128 * r bytes in
+memory.
+*/
+pub struct Scrypt {
+    n: usize,
+    r: usize,
+    p: usize,
+}
+
+impl Scrypt {
+    pub fn new(n: usize, r: usize, p: usize) -> Result<Self, KdfError> {
+        if r == 0 {
+            return Err(KdfError::InvalidBlockSizeError);
+        }
+        if p == 0 || p as u64 * r as u64 >= 1 << 30 {
+            return Err(KdfError::InvalidParallelismError);
+        }
+        if n < 2
+            || !n.is_power_of_two()
+            || (r < 4 && n as u64 >= 1 << (16 * r))
+            || n.checked_mul(128 * r).is_none()
+        {
+            return Err(KdfError::InvalidCostError);
+        }
+        Ok(Self { n, r, p })
+    }
+
+    pub fn derive(&self, password: &[u8], salt: &[u8], length: usize) -> Result<Digest, KdfError> {
+        let block_length: usize = 128 * self.r;
+        let pbkdf2: PBKDF2<SHA256> = PBKDF2::new(SHA256, 1)?;
+        let b: Digest = pbkdf2.derive(password, salt, self.p * block_length)?;
+
+        let mut b: Vec<u8> = b.to_slice().to_vec();
+        for block in b.chunks_exact_mut(block_length) {
+            ro_mix(block, self.n, self.r);
+        }
+        pbkdf2.derive(password, &b, length)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hash::Message;
+
+    #[test]
+    fn test_salsa20_8_on_rfc7914_suite() {
+        let input = Message::from_hex("7e879a214f3ec9867ca940e641718f26baee555b8c61c1b50df846116dcd3b1dee24f319df9b3d8514121e4b5ac5aa3276021d2909c74829edebc68db8b8c25e").unwrap();
+        let mut b: [u32; 16] = [0; 16];
+        for (b, chunk) in b.iter_mut().zip(input.to_slice().chunks_exact(4)) {
+            *b = u32::from_le_bytes(chunk.try_into().unwrap());
+        }
+        salsa20_8(&mut b);
+        let output: Vec<u8> = b.iter().flat_map(|word| word.to_le_bytes()).collect();
+        assert_eq!(
+            Message::from_slice(&output).to_hex(),
+            "a41f859c6608cc993b81cacb020cef05044b2181a2fd337dfd7b1c6396682f29b4393168e3c9e6bcfe6bc5b7a06d96bae424cc102c91745c24ad673dc7618f81"
+        );
+    }
+
+    #[test]
+    fn test_scrypt_on_rfc7914_suite() {
+        let tests = [
+            ("", "", 16, 1, 1, "77d6576238657b203b19ca42c18a0497f16b4844e3074ae8dfdffa3fede21442fcd0069ded0948f8326a753a0fc81f17e8d3e0fb2e0d3628cf35e20c38d18906"),
+            ("password", "NaCl", 1024, 8, 16, "fdbabe1c9d3472007856e7190d01e9fe7c6ad7cbc8237830e77376634b3731622eaf30d92e22a3886ff109279d9830dac727afb94a83ee6d8360cbdfa2cc0640"),
+            ("pleaseletmein", "SodiumChloride", 16384, 8, 1, "7023bdcb3afd7348461c06cd81fd38ebfda8fbba904f8e3ea9b543f6545da1f2d5432955613f0fcf62d49705242a9af9e61e85dc0d651e40dfcf017b45575887"),
+        ];
+
+        for (password, salt, n, r, p, expected) in tests {
+            let scrypt = Scrypt::new(n, r, p).unwrap();
+            assert_eq!(
+                scrypt
+                    .derive(password.as_bytes(), salt.as_bytes(), 64)
+                    .unwrap()
+                    .to_hex(),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_scrypt_invalid_parameters() {
+        assert!(Scrypt::new(0, 8, 1).is_err());
+        assert!(Scrypt::new(1, 8, 1).is_err());
+        assert!(Scrypt::new(1000, 8, 1).is_err());
+        assert!(Scrypt::new(1 << 16, 1, 1).is_err());
+        assert!(Scrypt::new(1 << 15, 1, 1).is_ok());
+        assert!(Scrypt::new(1024, 0, 1).is_err());
+        assert!(Scrypt::new(1024, 8, 0).is_err());
+        assert!(Scrypt::new(1024, 1 << 15, 1 << 15).is_err());
+    }
+}
@@ -0,0 +1,76 @@
+use crate::hash::{Digest, HashFunction, Message};
+use crate::kdf::KdfError;
+
+/*
+The key derivation function of ANSI X9.63 (also SEC 1), hashing the
+shared secret followed by a 32-bit big-endian counter starting at one
+and the shared info.
+*/
+pub struct X963KDF<H: HashFunction> {
+    hasher: H,
+}
+
+impl<H: HashFunction> X963KDF<H> {
+    pub fn new(hasher: H) -> Self {
+        Self { hasher }
+    }
+
+    pub fn derive(
+        &self,
+        shared_secret: &[u8],
+        shared_info: &[u8],
+        length: usize,
+    ) -> Result<Digest, KdfError> {
+        let hash_length: usize = self.hasher.hash(&Message::new()).to_slice().len();
+        if length == 0 || length.div_ceil(hash_length) as u64 > u32::MAX as u64 {
+            return Err(KdfError::InvalidOutputLengthError);
+        }
+
+        let mut output: Vec<u8> = Vec::with_capacity(length + hash_length);
+        let mut counter: u32 = 1;
+        while output.len() < length {
+            let mut input: Message = Message::from_slice(shared_secret);
+            input.extend_from_slice(&counter.to_be_bytes());
+            input.extend_from_slice(shared_info);
+            output.extend_from_slice(self.hasher.hash(&input).to_slice());
+            counter += 1;
+        }
+        output.truncate(length);
+        Ok(Digest::from_u8(&output))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hash::sha1::SHA1;
+    use crate::hash::sha256::SHA256;
+
+    #[test]
+    fn test_x963_kdf_on_cavs_suite() {
+        let kdf = X963KDF::new(SHA256);
+        let z = Message::from_hex("96c05619d56c328ab95fe84b18264b08725b85e33fd34f08").unwrap();
+        assert_eq!(
+            kdf.derive(z.to_slice(), &[], 16).unwrap().to_hex(),
+            "443024c3dae66b95e6f5670601558f71"
+        );
+
+        let z = Message::from_hex("22518b10e70f2a3f243810ae3254139efbee04aa57c7af7d").unwrap();
+        let shared_info = Message::from_hex("75eef81aa3041e33b80971203d2c0c52").unwrap();
+        assert_eq!(
+            kdf.derive(z.to_slice(), shared_info.to_slice(), 128).unwrap().to_hex(),
+            "c498af77161cc59f2962b9a713e2b215152d139766ce34a776df11866a69bf2e52a13d9c7c6fc878c50c5ea0bc7b00e0da2447cfd874f6cf92f30d0097111485500c90c3af8b487872d04685d14c8d1dc8d7fa08beb0ce0ababc11f0bd496269142d43525a78e5bc79a17f59676a5706dc54d54d4d1f0bd7e386128ec26afc21"
+        );
+    }
+
+    #[test]
+    fn test_x963_kdf_sha1() {
+        let kdf = X963KDF::new(SHA1);
+        let z = Message::from_hex("96c05619d56c328ab95fe84b18264b08725b85e33fd34f08").unwrap();
+        assert_eq!(
+            kdf.derive(z.to_slice(), b"abc", 32).unwrap().to_hex(),
+            "bf0afa06ed5a8f1d580d46e8fbd3cf0c50004f8d05eded8a94d79ee77ffa1eb8"
+        );
+        assert!(kdf.derive(z.to_slice(), b"abc", 0).is_err());
+    }
+}
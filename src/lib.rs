@@ -1,7 +1,66 @@
+pub mod checksum;
 pub mod cipher;
+pub mod fasthash;
 pub mod hash;
+pub mod kdf;
+pub mod mac;
+pub mod password;
+pub mod secret;
 
-pub use hash::{Digest, HashError, Message};
+pub use hash::{Digest, HashError, HashFunction, Message};
+
+pub use checksum::adler32::Adler32;
+pub use checksum::crc::{CRC, CRC32, CRC32BZIP2, CRC32C, CRC32MPEG2, CRC32POSIX, CRC64, CRC64XZ};
+pub use checksum::fletcher::{Fletcher16, Fletcher32};
+pub use checksum::ChecksumError;
+
+pub use cipher::aes::AES;
+pub use cipher::blowfish::Blowfish;
+pub use cipher::cbc::CBC;
+pub use cipher::cfb::CFB;
+pub use cipher::ctr::CTR;
+pub use cipher::ecb::ECB;
+pub use cipher::ofb::OFB;
+pub use cipher::padding::Padding;
+pub use cipher::threefish::Threefish;
+pub use cipher::{BlockCipher, CipherError};
+
+pub use kdf::argon2::{Argon2, Argon2Variant};
+pub use kdf::concat::ConcatKDF;
+pub use kdf::hkdf::HKDF;
+pub use kdf::kbkdf::{KbkdfMode, KBKDF};
+pub use kdf::pbkdf2::PBKDF2;
+pub use kdf::scrypt::Scrypt;
+pub use kdf::x963::X963KDF;
+pub use kdf::{CostLimits, KdfError};
+
+pub use password::bcrypt::{Bcrypt, BcryptVersion};
+pub use password::crypt::{Crypt, CryptAlgorithm, ShadowEntry};
+pub use password::phc::PhcString;
+pub use password::{needs_rehash, verify_password, verify_password_with_limits, PasswordError};
+
+pub use fasthash::fnv::{FNV1a32, FNV1a64};
+pub use fasthash::murmur3::{Murmur3X64_128, Murmur3X86_32};
+pub use fasthash::xxhash::{XXH3_128, XXH3_64, XXH64};
+
+pub use mac::cmac::{AesCmacPrf128, CMAC};
+pub use mac::ghash::GHASH;
+pub use mac::gmac::GMAC;
+pub use mac::hmac::HMAC;
+pub use mac::poly1305::Poly1305;
+pub use mac::{MacError, MacFunction};
+
+#[cfg(all(feature = "mlock", target_os = "linux"))]
+pub use secret::SecretBuffer;
+pub use secret::SecretError;
+
+pub use hash::ascon::{AsconCxof128, AsconHash256, AsconXof128};
+pub use hash::blake2b::{BLAKE2b, BLAKE2b256, BLAKE2b512};
+
+pub use hash::groestl::{Groestl, Groestl224, Groestl256, Groestl384, Groestl512};
+pub use hash::has160::HAS160;
+pub use hash::haval::HAVAL;
+pub use hash::jh::{JH, JH224, JH256, JH384, JH512};
 
 pub use hash::md2::MD2;
 pub use hash::md4::MD4;
@@ -17,3 +76,10 @@ pub use hash::sha384::SHA384;
 pub use hash::sha512::SHA512;
 pub use hash::sha512_224::SHA512_224;
 pub use hash::sha512_256::SHA512_256;
+pub use hash::sha512_t::SHA512T;
+
+pub use hash::siphash::{HalfSipHash, RandomSipState, SipHash, SipHash13, SipHash24, SipHasher};
+pub use hash::skein::{Skein, Skein1024, Skein256, Skein512};
+
+pub use hash::sm3::SM3;
+pub use hash::streebog::{Streebog256, Streebog512};
@@ -0,0 +1,39 @@
+use std::{error, fmt};
+
+use crate::hash::{Digest, Message};
+
+pub mod cmac;
+pub mod ghash;
+pub mod gmac;
+pub mod hmac;
+pub mod poly1305;
+
+#[derive(Debug)]
+pub enum MacError {
+    InvalidKeyLengthError,
+    InvalidNonceLengthError,
+    UnsupportedBlockSizeError,
+}
+
+impl fmt::Display for MacError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MacError::InvalidKeyLengthError => write!(f, "Invalid key length provided"),
+            MacError::InvalidNonceLengthError => write!(f, "Invalid nonce length provided"),
+            MacError::UnsupportedBlockSizeError => {
+                write!(f, "Block size of the cipher is not supported")
+            }
+        }
+    }
+}
+
+impl error::Error for MacError {}
+
+/*
+Common interface over the keyed MACs of this module that can serve as a
+pseudorandom function, used by constructions such as the SP 800-108 key
+derivation functions that work with any of them.
+*/
+pub trait MacFunction {
+    fn mac(&self, input: &Message) -> Digest;
+}
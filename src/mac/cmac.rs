@@ -0,0 +1,185 @@
+use crate::cipher::aes::AES;
+use crate::cipher::BlockCipher;
+use crate::hash::{Digest, Message};
+use crate::mac::{MacError, MacFunction};
+use crate::secret;
+
+/*
+Doubling in GF(2^n) used to derive the subkeys: the block is shifted
+left by one bit and, when the top bit was set, reduced by the low-weight
+irreducible polynomial of the block size.
+*/
+fn double(block: &[u8], polynomial: &[u8]) -> Vec<u8> {
+    let mut output: Vec<u8> = block
+        .iter()
+        .zip(block.iter().skip(1).chain([&0]))
+        .map(|(a, b)| (a << 1) | (b >> 7))
+        .collect();
+    if block[0] & 0x80 != 0 {
+        let start: usize = output.len() - polynomial.len();
+        for (o, p) in output[start..].iter_mut().zip(polynomial) {
+            *o ^= p;
+        }
+    }
+    output
+}
+
+fn xor(a: &[u8], b: &[u8]) -> Vec<u8> {
+    a.iter().zip(b).map(|(a, b)| a ^ b).collect()
+}
+
+/*
+CMAC (OMAC1) as specified in NIST SP 800-38B and RFC 4493, over any
+block cipher of the crate with a 64, 128, 256, 512 or 1024-bit block.
+*/
+pub struct CMAC<C: BlockCipher> {
+    cipher: C,
+    k1: Vec<u8>,
+    k2: Vec<u8>,
+}
+
+impl<C: BlockCipher> CMAC<C> {
+    pub fn new(cipher: C) -> Result<Self, MacError> {
+        let polynomial: &[u8] = match cipher.block_size() {
+            8 => &[0x1b],
+            16 => &[0x87],
+            32 => &[0x04, 0x25],
+            64 => &[0x01, 0x25],
+            128 => &[0x08, 0x00, 0x43],
+            _ => return Err(MacError::UnsupportedBlockSizeError),
+        };
+        let l: Vec<u8> = cipher.encrypt_block(&vec![0; cipher.block_size()]).unwrap();
+        let k1: Vec<u8> = double(&l, polynomial);
+        let k2: Vec<u8> = double(&k1, polynomial);
+        Ok(Self { cipher, k1, k2 })
+    }
+
+    pub fn mac(&self, input: &Message) -> Digest {
+        let block_size: usize = self.cipher.block_size();
+        let input: &[u8] = input.to_slice();
+        let num_blocks: usize = input.len().div_ceil(block_size).max(1);
+        let (blocks, last_block) = input.split_at((num_blocks - 1) * block_size);
+
+        let last_block: Vec<u8> = if last_block.len() == block_size {
+            xor(last_block, &self.k1)
+        } else {
+            let mut padded: Vec<u8> = last_block.to_vec();
+            padded.push(0x80);
+            padded.resize(block_size, 0x00);
+            xor(&padded, &self.k2)
+        };
+
+        let mut x: Vec<u8> = vec![0; block_size];
+        for block in blocks
+            .chunks_exact(block_size)
+            .chain([last_block.as_slice()])
+        {
+            x = self.cipher.encrypt_block(&xor(&x, block)).unwrap();
+        }
+        Digest::from_u8(&x)
+    }
+}
+
+impl<C: BlockCipher> MacFunction for CMAC<C> {
+    fn mac(&self, input: &Message) -> Digest {
+        CMAC::mac(self, input)
+    }
+}
+
+impl<C: BlockCipher> Drop for CMAC<C> {
+    fn drop(&mut self) {
+        secret::zeroize(&mut self.k1);
+        secret::zeroize(&mut self.k2);
+    }
+}
+
+/*
+AES-CMAC-PRF-128 from RFC 4615, which accepts keys of any length. Keys
+that are not 128 bits long are first turned into one with AES-CMAC under
+the all-zero key.
+*/
+pub struct AesCmacPrf128 {
+    cmac: CMAC<AES>,
+}
+
+impl AesCmacPrf128 {
+    pub fn new(key: &[u8]) -> Self {
+        let key: Vec<u8> = if key.len() == 16 {
+            key.to_vec()
+        } else {
+            let cmac: CMAC<AES> = CMAC::new(AES::new(&[0; 16]).unwrap()).unwrap();
+            cmac.mac(&Message::from_slice(key)).to_slice().to_vec()
+        };
+        Self {
+            cmac: CMAC::new(AES::new(&key).unwrap()).unwrap(),
+        }
+    }
+
+    pub fn prf(&self, input: &Message) -> Digest {
+        self.cmac.mac(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: &str = "2b7e151628aed2a6abf7158809cf4f3c";
+    const MESSAGE: &str = "6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e5130c81c46a35ce411e5fbc1191a0a52eff69f2445df4f9b17ad2b417be66c3710";
+
+    fn aes_cmac() -> CMAC<AES> {
+        CMAC::new(AES::new(Message::from_hex(KEY).unwrap().to_slice()).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_aes_cmac_subkeys() {
+        let cmac = aes_cmac();
+
+        assert_eq!(
+            Message::from_slice(&cmac.k1).to_hex(),
+            "fbeed618357133667c85e08f7236a8de"
+        );
+        assert_eq!(
+            Message::from_slice(&cmac.k2).to_hex(),
+            "f7ddac306ae266ccf90bc11ee46d513b"
+        );
+    }
+
+    #[test]
+    fn test_aes_cmac_on_rfc4493_suite() {
+        let cmac = aes_cmac();
+        let message = Message::from_hex(MESSAGE).unwrap();
+        let tests = [
+            (0, "bb1d6929e95937287fa37d129b756746"),
+            (16, "070a16b46b4d4144f79bdd9dd04a287c"),
+            (40, "dfa66747de9ae63030ca32611497c827"),
+            (64, "51f0bebf7e3b9d92fc49741779363cfe"),
+        ];
+
+        for (length, expected) in tests {
+            let input = Message::from_slice(&message.to_slice()[..length]);
+            assert_eq!(cmac.mac(&input).to_hex(), expected);
+        }
+    }
+
+    #[test]
+    fn test_aes_cmac_prf_128_on_rfc4615_suite() {
+        let input = Message::from_hex("000102030405060708090a0b0c0d0e0f10111213").unwrap();
+        let tests = [
+            (
+                "000102030405060708090a0b0c0d0e0fedcb",
+                "84a348a4a45d235babfffc0d2b4da09a",
+            ),
+            (
+                "000102030405060708090a0b0c0d0e0f",
+                "980ae87b5f4c9c5214f5b6a8455e4c2d",
+            ),
+            ("00010203040506070809", "290d9e112edb09ee141fcf64c0b72f3d"),
+        ];
+
+        for (key, expected) in tests {
+            let prf = AesCmacPrf128::new(Message::from_hex(key).unwrap().to_slice());
+            assert_eq!(prf.prf(&input).to_hex(), expected);
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use crate::hash::{Digest, Message};
+use crate::mac::MacError;
+use crate::secret;
+
+const BLOCK_SIZE: usize = 16;
+const R: u128 = 0xe1 << 120;
+
+/*
+Multiplication in GF(2^128) with the bit ordering of GCM, where the first
+bit of a block is the coefficient of x^0. Blocks are read as big-endian
+integers so that a right shift multiplies by x. Every step uses masks
+derived from the bits instead of branching, so the running time does not
+depend on the key or the data.
+*/
+pub(crate) fn gf_mul(x: u128, y: u128) -> u128 {
+    let mut z: u128 = 0;
+    let mut v: u128 = y;
+    for i in (0..128).rev() {
+        z ^= v & ((x >> i) & 1).wrapping_neg();
+        v = (v >> 1) ^ (R & (v & 1).wrapping_neg());
+    }
+    z
+}
+
+fn to_block(bytes: &[u8]) -> u128 {
+    let mut block: [u8; BLOCK_SIZE] = [0; BLOCK_SIZE];
+    block[..bytes.len()].copy_from_slice(bytes);
+    u128::from_be_bytes(block)
+}
+
+/*
+GHASH keyed with the hash subkey H. Data that does not fill a whole
+block is padded with zeros when the hash is finalized.
+*/
+#[derive(Clone)]
+pub struct GHASH {
+    h: u128,
+    y: u128,
+    buffer: Vec<u8>,
+}
+
+impl GHASH {
+    pub fn new(h: &[u8]) -> Result<Self, MacError> {
+        if h.len() != BLOCK_SIZE {
+            return Err(MacError::InvalidKeyLengthError);
+        }
+        Ok(Self {
+            h: to_block(h),
+            y: 0,
+            buffer: Vec::with_capacity(BLOCK_SIZE),
+        })
+    }
+
+    fn process_block(&mut self, block: &[u8]) {
+        self.y = gf_mul(self.y ^ to_block(block), self.h);
+    }
+
+    pub fn update(&mut self, input: &[u8]) {
+        self.buffer.extend_from_slice(input);
+        let num_blocks: usize = self.buffer.len() / BLOCK_SIZE;
+        let buffer: Vec<u8> = self.buffer.drain(..num_blocks * BLOCK_SIZE).collect();
+        for block in buffer.chunks_exact(BLOCK_SIZE) {
+            self.process_block(block);
+        }
+    }
+
+    /* Pads the buffered data with zeros, as done between the fields hashed by GCM. */
+    pub(crate) fn pad(&mut self) {
+        if !self.buffer.is_empty() {
+            let buffer: Vec<u8> = std::mem::take(&mut self.buffer);
+            self.process_block(&buffer);
+        }
+    }
+
+    pub fn finalize(mut self) -> Digest {
+        self.pad();
+        Digest::from_u8(&self.y.to_be_bytes())
+    }
+
+    pub fn mac(&self, input: &Message) -> Digest {
+        let mut ghash: GHASH = self.clone();
+        ghash.update(input.to_slice());
+        ghash.finalize()
+    }
+}
+
+impl Drop for GHASH {
+    fn drop(&mut self) {
+        secret::zeroize_value(&mut self.h);
+        secret::zeroize_value(&mut self.y);
+        secret::zeroize(&mut self.buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ghash() {
+        let ghash = GHASH::new(
+            Message::from_hex("66e94bd4ef8a2c3b884cfa59ca342b2e")
+                .unwrap()
+                .to_slice(),
+        )
+        .unwrap();
+        let input =
+            Message::from_hex("0388dace60b6a392f328c2b971b2fe7800000000000000000000000000000080")
+                .unwrap();
+        assert_eq!(
+            ghash.mac(&input).to_hex(),
+            "f38cbb1ad69223dcc3457ae5b6b0f885"
+        );
+
+        let ghash = GHASH::new(
+            Message::from_hex("b83b533708bf535d0aa6e52980d53b78")
+                .unwrap()
+                .to_slice(),
+        )
+        .unwrap();
+        let input: Vec<u8> = (0..100).collect();
+        assert_eq!(
+            ghash.mac(&Message::from_slice(&input)).to_hex(),
+            "8b5367f8d648922c7061edbcd5dcb85d"
+        );
+
+        let mut streaming = ghash.clone();
+        for chunk in input.chunks(7) {
+            streaming.update(chunk);
+        }
+        assert_eq!(
+            streaming.finalize().to_hex(),
+            "8b5367f8d648922c7061edbcd5dcb85d"
+        );
+    }
+}
@@ -0,0 +1,135 @@
+use crate::cipher::BlockCipher;
+use crate::hash::{Digest, Message};
+use crate::mac::ghash::GHASH;
+use crate::mac::MacError;
+use crate::secret;
+
+const BLOCK_SIZE: usize = 16;
+
+/*
+GMAC from NIST SP 800-38D, the authentication-only mode of GCM where the
+whole message is treated as additional authenticated data. It works over
+any block cipher of the crate with a 128-bit block. A 96-bit nonce is
+used directly as the pre-counter block, nonces of other lengths are
+hashed with GHASH first.
+*/
+pub struct GMAC<C: BlockCipher> {
+    cipher: C,
+    h: Vec<u8>,
+}
+
+impl<C: BlockCipher> GMAC<C> {
+    pub fn new(cipher: C) -> Result<Self, MacError> {
+        if cipher.block_size() != BLOCK_SIZE {
+            return Err(MacError::UnsupportedBlockSizeError);
+        }
+        let h: Vec<u8> = cipher.encrypt_block(&[0; BLOCK_SIZE]).unwrap();
+        Ok(Self { cipher, h })
+    }
+
+    fn lengths_block(a: usize, c: usize) -> [u8; BLOCK_SIZE] {
+        let mut block: [u8; BLOCK_SIZE] = [0; BLOCK_SIZE];
+        block[..8].copy_from_slice(&(a as u64 * 8).to_be_bytes());
+        block[8..].copy_from_slice(&(c as u64 * 8).to_be_bytes());
+        block
+    }
+
+    fn pre_counter_block(&self, nonce: &[u8]) -> Vec<u8> {
+        if nonce.len() == 12 {
+            let mut j0: Vec<u8> = nonce.to_vec();
+            j0.extend_from_slice(&[0, 0, 0, 1]);
+            return j0;
+        }
+        let mut ghash: GHASH = GHASH::new(&self.h).unwrap();
+        ghash.update(nonce);
+        ghash.pad();
+        ghash.update(&Self::lengths_block(0, nonce.len()));
+        ghash.finalize().to_slice().to_vec()
+    }
+
+    pub fn mac(&self, nonce: &[u8], input: &Message) -> Result<Digest, MacError> {
+        if nonce.is_empty() {
+            return Err(MacError::InvalidNonceLengthError);
+        }
+        let mut ghash: GHASH = GHASH::new(&self.h).unwrap();
+        ghash.update(input.to_slice());
+        ghash.pad();
+        ghash.update(&Self::lengths_block(input.to_slice().len(), 0));
+        let s: Digest = ghash.finalize();
+
+        let j0: Vec<u8> = self
+            .cipher
+            .encrypt_block(&self.pre_counter_block(nonce))
+            .unwrap();
+        let tag: Vec<u8> = j0.iter().zip(s.to_slice()).map(|(a, b)| a ^ b).collect();
+        Ok(Digest::from_u8(&tag))
+    }
+}
+
+impl<C: BlockCipher> Drop for GMAC<C> {
+    fn drop(&mut self) {
+        secret::zeroize(&mut self.h);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cipher::aes::AES;
+
+    const KEY: &str = "feffe9928665731c6d6a8f9467308308";
+    const AAD: &str = "feedfacedeadbeeffeedfacedeadbeefabaddad2";
+
+    fn gmac(key: &str) -> GMAC<AES> {
+        GMAC::new(AES::new(Message::from_hex(key).unwrap().to_slice()).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_aes_gmac_on_gcm_suite() {
+        let tests = [
+            (
+                "00000000000000000000000000000000",
+                "000000000000000000000000",
+                Message::new(),
+                "58e2fccefa7e3061367f1d57a4e7455a",
+            ),
+            (
+                KEY,
+                "cafebabefacedbaddecaf888",
+                Message::from_hex(AAD).unwrap(),
+                "346434fd51d5cd0c5887ec63e39b907a",
+            ),
+            (
+                KEY,
+                "cafebabefacedbad",
+                Message::from_hex(AAD).unwrap(),
+                "ef6995e531e81a01f5b2f7762cc60bd2",
+            ),
+            (
+                KEY,
+                "9313225df88406e555909c5aff5269aa6a7a9538534f7da1e4c303d2a318a728c3c0c95156809539fcf0e2429a6b525416aedbf5a0de6a57a637b39b",
+                Message::from_hex(AAD).unwrap(),
+                "7be5178ff2b73c7d6f8b4dfdde8437ec",
+            ),
+            (
+                KEY,
+                "cafebabefacedbaddecaf888",
+                Message::from_slice(&(0..100).collect::<Vec<u8>>()),
+                "d57ff5caf5b6fe991a56c69bf9fbc3b7",
+            ),
+        ];
+
+        for (key, nonce, input, expected) in tests {
+            let nonce = Message::from_hex(nonce).unwrap();
+            assert_eq!(
+                gmac(key).mac(nonce.to_slice(), &input).unwrap().to_hex(),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_gmac_invalid_parameters() {
+        assert!(gmac(KEY).mac(&[], &Message::new()).is_err());
+    }
+}
@@ -0,0 +1,200 @@
+use crate::hash::{Digest, HashFunction, Message};
+use crate::mac::MacFunction;
+use crate::secret;
+
+const IPAD: u8 = 0x36;
+const OPAD: u8 = 0x5c;
+
+/*
+HMAC as specified in RFC 2104, keyed with any hash function of the crate.
+Keys longer than the block size of the hash function are hashed first,
+shorter keys are padded with zeros up to the block size.
+
+The hash functions of the crate work on whole messages, so the
+incremental API buffers the data passed to update and hashes it when the
+MAC is finalized.
+*/
+pub struct HMAC<H: HashFunction> {
+    hasher: H,
+    key: Vec<u8>,
+    inner: Message,
+}
+
+impl<H: HashFunction> HMAC<H> {
+    pub fn new(hasher: H, key: &[u8]) -> Self {
+        let block_size: usize = hasher.block_size();
+        let mut key: Vec<u8> = if key.len() > block_size {
+            hasher.hash(&Message::from_slice(key)).to_slice().to_vec()
+        } else {
+            key.to_vec()
+        };
+        key.resize(block_size, 0x00);
+
+        let inner: Message = Self::padded_key(&key, IPAD);
+        Self { hasher, key, inner }
+    }
+
+    fn padded_key(key: &[u8], pad: u8) -> Message {
+        Message::from_slice(&key.iter().map(|k| k ^ pad).collect::<Vec<u8>>())
+    }
+
+    fn outer(&self, inner: &Message) -> Digest {
+        let inner_digest: Digest = self.hasher.hash(inner);
+        let mut outer: Message = Self::padded_key(&self.key, OPAD);
+        outer.extend_from_slice(inner_digest.to_slice());
+        self.hasher.hash(&outer)
+    }
+
+    pub fn mac(&self, input: &Message) -> Digest {
+        let mut inner: Message = Self::padded_key(&self.key, IPAD);
+        inner.extend_from_slice(input.to_slice());
+        self.outer(&inner)
+    }
+
+    pub fn update(&mut self, input: &[u8]) {
+        self.inner.extend_from_slice(input);
+    }
+
+    pub fn finalize(self) -> Digest {
+        self.outer(&self.inner)
+    }
+}
+
+impl<H: HashFunction> MacFunction for HMAC<H> {
+    fn mac(&self, input: &Message) -> Digest {
+        HMAC::mac(self, input)
+    }
+}
+
+impl<H: HashFunction> Drop for HMAC<H> {
+    fn drop(&mut self) {
+        secret::zeroize(&mut self.key);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hash::md5::MD5;
+    use crate::hash::sha1::SHA1;
+    use crate::hash::sha224::SHA224;
+    use crate::hash::sha256::SHA256;
+    use crate::hash::sha3::{SHA3_256, SHA3_512};
+    use crate::hash::sha384::SHA384;
+    use crate::hash::sha512::SHA512;
+
+    const LONG_DATA: &str = "This is a test using a larger than block-size key and a larger than block-size data. The key needs to be hashed before being used by the HMAC algorithm.";
+
+    #[test]
+    fn test_hmac_sha2_on_rfc4231_suite() {
+        let tests = [
+            (vec![0x0b; 20], Message::from_string("Hi There"), [
+                "896fb1128abbdf196832107cd49df33f47b4b1169912ba4f53684b22",
+                "b0344c61d8db38535ca8afceaf0bf12b881dc200c9833da726e9376c2e32cff7",
+                "afd03944d84895626b0825f4ab46907f15f9dadbe4101ec682aa034c7cebc59cfaea9ea9076ede7f4af152e8b2fa9cb6",
+                "87aa7cdea5ef619d4ff0b4241a1d6cb02379f4e2ce4ec2787ad0b30545e17cdedaa833b7d6b8a702038b274eaea3f4e4be9d914eeb61f1702e696c203a126854",
+            ]),
+            (b"Jefe".to_vec(), Message::from_string("what do ya want for nothing?"), [
+                "a30e01098bc6dbbf45690f3a7e9e6d0f8bbea2a39e6148008fd05e44",
+                "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843",
+                "af45d2e376484031617f78d2b58a6b1b9c7ef464f5a01b47e42ec3736322445e8e2240ca5e69e2c78b3239ecfab21649",
+                "164b7a7bfcf819e2e395fbe73b56e0a387bd64222e831fd610270cd7ea2505549758bf75c05a994a6d034f65f8f0e6fdcaeab1a34d4a6b4b636e070a38bce737",
+            ]),
+            (vec![0xaa; 20], Message::from_slice(&[0xdd; 50]), [
+                "7fb3cb3588c6c1f6ffa9694d7d6ad2649365b0c1f65d69d1ec8333ea",
+                "773ea91e36800e46854db8ebd09181a72959098b3ef8c122d9635514ced565fe",
+                "88062608d3e6ad8a0aa2ace014c8a86f0aa635d947ac9febe83ef4e55966144b2a5ab39dc13814b94e3ab6e101a34f27",
+                "fa73b0089d56a284efb0f0756c890be9b1b5dbdd8ee81a3655f83e33b2279d39bf3e848279a722c806b485a47e67c807b946a337bee8942674278859e13292fb",
+            ]),
+            ((1..=25).collect(), Message::from_slice(&[0xcd; 50]), [
+                "6c11506874013cac6a2abc1bb382627cec6a90d86efc012de7afec5a",
+                "82558a389a443c0ea4cc819899f2083a85f0faa3e578f8077a2e3ff46729665b",
+                "3e8a69b7783c25851933ab6290af6ca77a9981480850009cc5577c6e1f573b4e6801dd23c4a7d679ccf8a386c674cffb",
+                "b0ba465637458c6990e5a8c5f61d4af7e576d97ff94b872de76f8050361ee3dba91ca5c11aa25eb4d679275cc5788063a5f19741120c4f2de2adebeb10a298dd",
+            ]),
+            (vec![0xaa; 131], Message::from_string("Test Using Larger Than Block-Size Key - Hash Key First"), [
+                "95e9a0db962095adaebe9b2d6f0dbce2d499f112f2d2b7273fa6870e",
+                "60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54",
+                "4ece084485813e9088d2c63a041bc5b44f9ef1012a2b588f3cd11f05033ac4c60c2ef6ab4030fe8296248df163f44952",
+                "80b24263c7c1a3ebb71493c1dd7be8b49b46d1f41b4aeec1121b013783f8f3526b56d037e05f2598bd0fd2215d6a1e5295e64f73f63f0aec8b915a985d786598",
+            ]),
+            (vec![0xaa; 131], Message::from_string(LONG_DATA), [
+                "3a854166ac5d9f023f54d517d0b39dbd946770db9c2b95c9f6f565d1",
+                "9b09ffa71b942fcb27635fbcd5b0e944bfdc63644f0713938a7f51535c3a35e2",
+                "6617178e941f020d351e2f254e8fd32c602420feb0b8fb9adccebb82461e99c5a678cc31e799176d3860e6110c46523e",
+                "e37b6a775dc87dbaa4dfa9f96e5e3ffddebd71f8867289865df5a32d20cdc944b6022cac3c4982b10d5eeb55c3e4de15134676fb6de0446065c97440fa8c6a58",
+            ]),
+        ];
+
+        for (key, data, expected) in tests {
+            assert_eq!(HMAC::new(SHA224, &key).mac(&data).to_hex(), expected[0]);
+            assert_eq!(HMAC::new(SHA256, &key).mac(&data).to_hex(), expected[1]);
+            assert_eq!(HMAC::new(SHA384, &key).mac(&data).to_hex(), expected[2]);
+            assert_eq!(HMAC::new(SHA512, &key).mac(&data).to_hex(), expected[3]);
+        }
+    }
+
+    #[test]
+    fn test_hmac_md5_and_sha1_on_rfc2202_suite() {
+        let data = Message::from_string("Hi There");
+        assert_eq!(
+            HMAC::new(MD5, &[0x0b; 16]).mac(&data).to_hex(),
+            "9294727a3638bb1c13f48ef8158bfc9d"
+        );
+        assert_eq!(
+            HMAC::new(SHA1, &[0x0b; 20]).mac(&data).to_hex(),
+            "b617318655057264e28bc0b6fb378c8ef146be00"
+        );
+
+        let data = Message::from_string("what do ya want for nothing?");
+        assert_eq!(
+            HMAC::new(MD5, b"Jefe").mac(&data).to_hex(),
+            "750c783e6ab0b503eaa86e310a5db738"
+        );
+        assert_eq!(
+            HMAC::new(SHA1, b"Jefe").mac(&data).to_hex(),
+            "effcdf6ae5eb2fa2d27416d5f184df9c259a7c79"
+        );
+
+        let data = Message::from_string("Test Using Larger Than Block-Size Key - Hash Key First");
+        assert_eq!(
+            HMAC::new(MD5, &[0xaa; 80]).mac(&data).to_hex(),
+            "6b1ab7fe4bd7bf8f0b62e6ce61b9d0cd"
+        );
+        assert_eq!(
+            HMAC::new(SHA1, &[0xaa; 80]).mac(&data).to_hex(),
+            "aa4ae5e15272d00e95705637ce8a3b55ed402112"
+        );
+    }
+
+    #[test]
+    fn test_hmac_sha3() {
+        let data = Message::from_string("what do ya want for nothing?");
+        assert_eq!(
+            HMAC::new(SHA3_256::new(), b"Jefe").mac(&data).to_hex(),
+            "c7d4072e788877ae3596bbb0da73b887c9171f93095b294ae857fbe2645e1ba5"
+        );
+        assert_eq!(
+            HMAC::new(SHA3_512::new(), b"Jefe").mac(&data).to_hex(),
+            "5a4bfeab6166427c7a3647b747292b8384537cdb89afb3bf5665e4c5e709350b287baec921fd7ca0ee7a0c31d022a95e1fc92ba9d77df883960275beb4e62024"
+        );
+
+        let data = Message::from_string("Hi There");
+        assert_eq!(
+            HMAC::new(SHA3_256::new(), &[0xaa; 200]).mac(&data).to_hex(),
+            "63f1b35c7152a5b61aea75465df24cebd1e6996c0a67905ea8c499b07f600354"
+        );
+    }
+
+    #[test]
+    fn test_hmac_incremental() {
+        let data = Message::from_string(LONG_DATA);
+        let expected = HMAC::new(SHA256, &[0xaa; 131]).mac(&data);
+
+        let mut hmac = HMAC::new(SHA256, &[0xaa; 131]);
+        for chunk in data.to_slice().chunks(7) {
+            hmac.update(chunk);
+        }
+        assert_eq!(hmac.finalize().to_hex(), expected.to_hex());
+    }
+}
@@ -0,0 +1,237 @@
+use crate::hash::{Digest, Message};
+use crate::mac::MacError;
+use crate::secret;
+
+const BLOCK_SIZE: usize = 16;
+const KEY_LENGTH: usize = 32;
+const LIMB_MASK: u32 = 0x3ffffff;
+
+fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap())
+}
+
+/*
+Poly1305 from RFC 8439. The accumulator h and the clamped key r are kept
+in five 26-bit limbs, so every product fits in a u64 and the reduction
+modulo 2^130 - 5 only needs shifts, masks and a multiplication by 5.
+
+The key must only be used for a single message.
+*/
+#[derive(Clone)]
+pub struct Poly1305 {
+    r: [u32; 5],
+    s: [u32; 4],
+    h: [u32; 5],
+    buffer: Vec<u8>,
+}
+
+impl Poly1305 {
+    pub fn new(key: &[u8]) -> Result<Self, MacError> {
+        if key.len() != KEY_LENGTH {
+            return Err(MacError::InvalidKeyLengthError);
+        }
+        Ok(Self {
+            r: [
+                read_u32(key, 0) & 0x3ffffff,
+                (read_u32(key, 3) >> 2) & 0x3ffff03,
+                (read_u32(key, 6) >> 4) & 0x3ffc0ff,
+                (read_u32(key, 9) >> 6) & 0x3f03fff,
+                (read_u32(key, 12) >> 8) & 0x00fffff,
+            ],
+            s: std::array::from_fn(|i| read_u32(key, 16 + 4 * i)),
+            h: [0; 5],
+            buffer: Vec::with_capacity(BLOCK_SIZE),
+        })
+    }
+
+    fn process_block(&mut self, block: &[u8], hibit: u32) {
+        let r: [u64; 5] = self.r.map(|r| r as u64);
+        let [s1, s2, s3, s4]: [u64; 4] = [r[1] * 5, r[2] * 5, r[3] * 5, r[4] * 5];
+
+        let h: [u64; 5] = [
+            (self.h[0] + (read_u32(block, 0) & LIMB_MASK)) as u64,
+            (self.h[1] + ((read_u32(block, 3) >> 2) & LIMB_MASK)) as u64,
+            (self.h[2] + ((read_u32(block, 6) >> 4) & LIMB_MASK)) as u64,
+            (self.h[3] + ((read_u32(block, 9) >> 6) & LIMB_MASK)) as u64,
+            (self.h[4] + ((read_u32(block, 12) >> 8) | hibit)) as u64,
+        ];
+
+        let mut d: [u64; 5] = [
+            h[0] * r[0] + h[1] * s4 + h[2] * s3 + h[3] * s2 + h[4] * s1,
+            h[0] * r[1] + h[1] * r[0] + h[2] * s4 + h[3] * s3 + h[4] * s2,
+            h[0] * r[2] + h[1] * r[1] + h[2] * r[0] + h[3] * s4 + h[4] * s3,
+            h[0] * r[3] + h[1] * r[2] + h[2] * r[1] + h[3] * r[0] + h[4] * s4,
+            h[0] * r[4] + h[1] * r[3] + h[2] * r[2] + h[3] * r[1] + h[4] * r[0],
+        ];
+
+        for i in 0..4 {
+            d[i + 1] += d[i] >> 26;
+            self.h[i] = d[i] as u32 & LIMB_MASK;
+        }
+        self.h[4] = d[4] as u32 & LIMB_MASK;
+        self.h[0] += (d[4] >> 26) as u32 * 5;
+        self.h[1] += self.h[0] >> 26;
+        self.h[0] &= LIMB_MASK;
+    }
+
+    pub fn update(&mut self, input: &[u8]) {
+        self.buffer.extend_from_slice(input);
+        let num_blocks: usize = self.buffer.len() / BLOCK_SIZE;
+        let buffer: Vec<u8> = self.buffer.drain(..num_blocks * BLOCK_SIZE).collect();
+        for block in buffer.chunks_exact(BLOCK_SIZE) {
+            self.process_block(block, 1 << 24);
+        }
+    }
+
+    pub fn finalize(mut self) -> Digest {
+        if !self.buffer.is_empty() {
+            let mut block: Vec<u8> = self.buffer.clone();
+            block.push(0x01);
+            block.resize(BLOCK_SIZE, 0x00);
+            self.process_block(&block, 0);
+        }
+
+        let mut h: [u32; 5] = self.h;
+        for i in 1..5 {
+            h[i] += h[i - 1] >> 26;
+            h[i - 1] &= LIMB_MASK;
+        }
+        h[0] += (h[4] >> 26) * 5;
+        h[4] &= LIMB_MASK;
+        h[1] += h[0] >> 26;
+        h[0] &= LIMB_MASK;
+
+        /* Computes h - p and keeps it when it does not underflow, without branching. */
+        let mut g: [u32; 5] = [0; 5];
+        let mut carry: u32 = 5;
+        for i in 0..5 {
+            g[i] = h[i].wrapping_add(carry);
+            carry = g[i] >> 26;
+            g[i] &= LIMB_MASK;
+        }
+        g[4] = g[4].wrapping_add(carry << 26).wrapping_sub(1 << 26);
+        let mask: u32 = (g[4] >> 31).wrapping_sub(1);
+        for (h, g) in h.iter_mut().zip(&g) {
+            *h = (*h & !mask) | (g & mask);
+        }
+
+        let words: [u32; 4] = [
+            h[0] | (h[1] << 26),
+            (h[1] >> 6) | (h[2] << 20),
+            (h[2] >> 12) | (h[3] << 14),
+            (h[3] >> 18) | (h[4] << 8),
+        ];
+        let mut tag: Vec<u8> = Vec::with_capacity(BLOCK_SIZE);
+        let mut carry: u64 = 0;
+        for (word, s) in words.iter().zip(&self.s) {
+            carry += *word as u64 + *s as u64;
+            tag.extend_from_slice(&(carry as u32).to_le_bytes());
+            carry >>= 32;
+        }
+        Digest::from_u8(&tag)
+    }
+
+    pub fn mac(&self, input: &Message) -> Digest {
+        let mut poly1305: Poly1305 = self.clone();
+        poly1305.update(input.to_slice());
+        poly1305.finalize()
+    }
+
+    /* Compares the computed tag in constant time. */
+    pub fn verify(self, tag: &[u8]) -> bool {
+        self.finalize().verify(tag)
+    }
+}
+
+impl Drop for Poly1305 {
+    fn drop(&mut self) {
+        secret::zeroize_value(&mut self.r);
+        secret::zeroize_value(&mut self.s);
+        secret::zeroize_value(&mut self.h);
+        secret::zeroize(&mut self.buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn poly1305(key: &str) -> Poly1305 {
+        Poly1305::new(Message::from_hex(key).unwrap().to_slice()).unwrap()
+    }
+
+    #[test]
+    fn test_poly1305_on_rfc8439_suite() {
+        let tests = [
+            (
+                "85d6be7857556d337f4452fe42d506a80103808afb0db2fd4abff6af4149f51b",
+                Message::from_string("Cryptographic Forum Research Group"),
+                "a8061dc1305136c6c22b8baf0c0127a9",
+            ),
+            (
+                "0000000000000000000000000000000000000000000000000000000000000000",
+                Message::from_slice(&[0; 64]),
+                "00000000000000000000000000000000",
+            ),
+            (
+                "36e5f6b5c5e06070f0efca96227a863e00000000000000000000000000000000",
+                Message::from_string("Any submission to the IETF intended by the Contributor for publication as all or part of an IETF Internet-Draft or RFC and any statement made within the context of an IETF activity is considered an \"IETF Contribution\". Such statements include oral statements in IETF sessions, as well as written and electronic communications made at any time or place, which are addressed to"),
+                "f3477e7cd95417af89a6b8794c310cf0",
+            ),
+        ];
+
+        for (key, input, expected) in tests {
+            assert_eq!(poly1305(key).mac(&input).to_hex(), expected);
+        }
+    }
+
+    #[test]
+    fn test_poly1305_reduction_edge_cases() {
+        let r1 = "0100000000000000000000000000000000000000000000000000000000000000";
+        let r2 = "0200000000000000000000000000000000000000000000000000000000000000";
+        let tests = [
+            (r2, "ffffffffffffffffffffffffffffffff", "03000000000000000000000000000000"),
+            (
+                "02000000000000000000000000000000ffffffffffffffffffffffffffffffff",
+                "02000000000000000000000000000000",
+                "03000000000000000000000000000000",
+            ),
+            (
+                r1,
+                "fffffffffffffffffffffffffffffffff0ffffffffffffffffffffffffffffff11000000000000000000000000000000",
+                "05000000000000000000000000000000",
+            ),
+            (
+                r1,
+                "fffffffffffffffffffffffffffffffffbfefefefefefefefefefefefefefefe01010101010101010101010101010101",
+                "00000000000000000000000000000000",
+            ),
+            (r2, "fdffffffffffffffffffffffffffffff", "faffffffffffffffffffffffffffffff"),
+        ];
+
+        for (key, input, expected) in tests {
+            let input = Message::from_hex(input).unwrap();
+            assert_eq!(poly1305(key).mac(&input).to_hex(), expected);
+        }
+    }
+
+    #[test]
+    fn test_poly1305_streaming_and_verify() {
+        let key = "85d6be7857556d337f4452fe42d506a80103808afb0db2fd4abff6af4149f51b";
+        let input = b"Cryptographic Forum Research Group";
+        let tag = Message::from_hex("a8061dc1305136c6c22b8baf0c0127a9").unwrap();
+
+        let mut mac = poly1305(key);
+        for chunk in input.chunks(5) {
+            mac.update(chunk);
+        }
+        assert!(mac.verify(tag.to_slice()));
+
+        let mut mac = poly1305(key);
+        mac.update(input);
+        assert!(!mac.clone().verify(&[0; 16]));
+        assert!(!mac.verify(&tag.to_slice()[..15]));
+
+        assert!(Poly1305::new(&[0; 16]).is_err());
+    }
+}
@@ -0,0 +1,285 @@
+use std::{error, fmt};
+
+use crate::hash::sha1::SHA1;
+use crate::hash::sha256::SHA256;
+use crate::hash::sha512::SHA512;
+use crate::hash::{Digest, HashFunction};
+use crate::kdf::argon2::{self, Argon2};
+use crate::kdf::pbkdf2::PBKDF2;
+use crate::kdf::scrypt::Scrypt;
+use crate::kdf::{CostLimits, KdfError};
+use crate::password::bcrypt::Bcrypt;
+use crate::password::crypt::Crypt;
+use crate::password::phc::PhcString;
+
+pub mod bcrypt;
+pub mod crypt;
+pub mod phc;
+
+#[derive(Debug)]
+pub enum PasswordError {
+    InvalidCostError,
+    InvalidEncodingError,
+    InvalidPasswordLengthError,
+    InvalidSaltLengthError,
+    UnsupportedAlgorithmError,
+    WorkerThreadError,
+}
+
+impl fmt::Display for PasswordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PasswordError::InvalidCostError => write!(f, "Invalid cost provided"),
+            PasswordError::InvalidEncodingError => write!(f, "Invalid encoded hash provided"),
+            PasswordError::InvalidPasswordLengthError => {
+                write!(f, "Invalid password length provided")
+            }
+            PasswordError::InvalidSaltLengthError => write!(f, "Invalid salt length provided"),
+            PasswordError::UnsupportedAlgorithmError => {
+                write!(f, "Unsupported password hashing algorithm")
+            }
+            PasswordError::WorkerThreadError => write!(f, "Worker thread could not be run"),
+        }
+    }
+}
+
+impl error::Error for PasswordError {}
+
+impl From<KdfError> for PasswordError {
+    fn from(error: KdfError) -> Self {
+        match error {
+            KdfError::InvalidBlockSizeError
+            | KdfError::InvalidCostError
+            | KdfError::InvalidIterationCountError
+            | KdfError::InvalidParallelismError => PasswordError::InvalidCostError,
+            KdfError::InvalidSaltLengthError => PasswordError::InvalidSaltLengthError,
+            KdfError::WorkerThreadError => PasswordError::WorkerThreadError,
+            KdfError::InvalidCounterLengthError
+            | KdfError::InvalidEncodingError
+            | KdfError::InvalidKeyLengthError
+            | KdfError::InvalidOutputLengthError => PasswordError::InvalidEncodingError,
+        }
+    }
+}
+
+/*
+Checks a password against a stored hash in any of the formats the crate
+implements, picking the algorithm from the identifier in front of it:
+Argon2, scrypt and PBKDF2 in the PHC string format, bcrypt, and the
+md5-crypt, sha256-crypt and sha512-crypt formats. Argon2 and scrypt
+hashes whose parameters exceed the default CostLimits are rejected with
+InvalidCostError.
+*/
+pub fn verify_password(encoded: &str, password: &[u8]) -> Result<bool, PasswordError> {
+    verify_password_with_limits(encoded, password, &CostLimits::new())
+}
+
+/* Same as verify_password with caller-chosen limits on the Argon2 and scrypt costs. */
+pub fn verify_password_with_limits(
+    encoded: &str,
+    password: &[u8],
+    limits: &CostLimits,
+) -> Result<bool, PasswordError> {
+    let id: &str = encoded
+        .strip_prefix('$')
+        .and_then(|fields| fields.split('$').next())
+        .ok_or(PasswordError::InvalidEncodingError)?;
+    match id {
+        "argon2d" | "argon2i" | "argon2id" => Ok(Argon2::verify_encoded_with_limits(
+            encoded, password, limits,
+        )?),
+        "2a" | "2b" | "2y" => Bcrypt::verify(encoded, password),
+        "1" | "5" | "6" => Crypt::verify(encoded, password),
+        "scrypt" | "pbkdf2-sha1" | "pbkdf2-sha256" | "pbkdf2-sha512" => {
+            verify_phc(&PhcString::parse(encoded)?, password, limits)
+        }
+        _ => Err(PasswordError::UnsupportedAlgorithmError),
+    }
+}
+
+/*
+Tells whether a password that was just verified against the stored hash
+should be hashed again with the preferred Argon2 parameters, which is the
+case for every other algorithm and for Argon2 with different parameters.
+*/
+pub fn needs_rehash(encoded: &str, preferred: &Argon2) -> Result<bool, PasswordError> {
+    if !encoded.starts_with("$argon2") {
+        return Ok(true);
+    }
+    let phc: PhcString = PhcString::parse(encoded)?;
+    let param = |name: &str| phc.param(name).and_then(|value| value.parse::<u32>().ok());
+    Ok(phc.id() != preferred.variant().name()
+        || phc.version() != Some(argon2::VERSION)
+        || param("m") != Some(preferred.memory_cost())
+        || param("t") != Some(preferred.time_cost())
+        || param("p") != Some(preferred.lanes()))
+}
+
+fn verify_phc(
+    phc: &PhcString,
+    password: &[u8],
+    limits: &CostLimits,
+) -> Result<bool, PasswordError> {
+    let param = |name: &str| -> Result<u32, PasswordError> {
+        phc.param(name)
+            .and_then(|value| value.parse().ok())
+            .ok_or(PasswordError::InvalidEncodingError)
+    };
+    let (Some(salt), Some(hash)) = (phc.salt(), phc.hash()) else {
+        return Err(PasswordError::InvalidEncodingError);
+    };
+    if phc.version().is_some() || hash.is_empty() {
+        return Err(PasswordError::InvalidEncodingError);
+    }
+
+    let digest: Digest = match phc.id() {
+        "scrypt" => {
+            if phc.params().len() != 3 {
+                return Err(PasswordError::InvalidEncodingError);
+            }
+            let (ln, r, p): (u32, u32, u32) = (param("ln")?, param("r")?, param("p")?);
+            /* ROMix keeps N blocks of 128 * r bytes, next to the p blocks of B. */
+            let memory: u64 = 1u64
+                .checked_shl(ln)
+                .and_then(|n| n.checked_add(p as u64))
+                .and_then(|blocks| blocks.checked_mul(128 * r as u64))
+                .ok_or(PasswordError::InvalidCostError)?;
+            limits.check(memory, p as u64, 1)?;
+            let n: usize = 1usize
+                .checked_shl(ln)
+                .ok_or(PasswordError::InvalidCostError)?;
+            let scrypt: Scrypt = Scrypt::new(n, r as usize, p as usize)?;
+            scrypt.derive(password, salt, hash.len())?
+        }
+        "pbkdf2-sha1" => derive_pbkdf2(SHA1, phc, password, salt, hash.len())?,
+        "pbkdf2-sha256" => derive_pbkdf2(SHA256, phc, password, salt, hash.len())?,
+        "pbkdf2-sha512" => derive_pbkdf2(SHA512, phc, password, salt, hash.len())?,
+        _ => return Err(PasswordError::UnsupportedAlgorithmError),
+    };
+    Ok(digest.verify(hash))
+}
+
+fn derive_pbkdf2<H: HashFunction>(
+    hasher: H,
+    phc: &PhcString,
+    password: &[u8],
+    salt: &[u8],
+    length: usize,
+) -> Result<Digest, PasswordError> {
+    let iterations: u32 = phc
+        .param("i")
+        .and_then(|value| value.parse().ok())
+        .ok_or(PasswordError::InvalidEncodingError)?;
+    /* The output length is optional since it is implied by the hash. */
+    let expected: usize = match phc.param("l") {
+        Some(value) => value
+            .parse()
+            .map_err(|_| PasswordError::InvalidEncodingError)?,
+        None => length,
+    };
+    if expected != length || phc.params().len() != 1 + phc.param("l").is_some() as usize {
+        return Err(PasswordError::InvalidEncodingError);
+    }
+    Ok(PBKDF2::new(hasher, iterations)?.derive(password, salt, length)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kdf::argon2::Argon2Variant;
+
+    #[test]
+    fn test_verify_password_dispatch() {
+        let argon2: Argon2 = Argon2::new(Argon2Variant::Argon2id, 64, 1, 1).unwrap();
+        let tests = [
+            argon2
+                .hash_encoded(b"password", b"somesaltsomesalt", 32)
+                .unwrap(),
+            "$2b$04$abcdefghijklmnopqrstuughE8Ev8uGFaUgY2cNEySvxngrb/Jzdm".to_string(),
+            "$1$saltsalt$qjXMvbEw8oaL.CzflDtaK/".to_string(),
+            "$scrypt$ln=10,r=8,p=1$c29tZXNhbHRzb21lc2FsdA$dj05BT7oUTq35qmxXqG/pksYG8IJr8uxtvAzbfGjoic"
+                .to_string(),
+            "$pbkdf2-sha256$i=1000,l=32$c29tZXNhbHRzb21lc2FsdA$s5LQUeAEZUMuFVrnmF3OMNPXs3QWnF8SO/5BXmCj6QQ"
+                .to_string(),
+            "$pbkdf2-sha512$i=1000,l=64$c29tZXNhbHRzb21lc2FsdA$a5wgoWFIPKuJOEszqMEKfpxJMYmocERsHXaC6CvdkdaTNCkO6JxcKuDoNYXi3iPDLnjgJCAVtWtsfscGAZC0PQ"
+                .to_string(),
+        ];
+
+        for encoded in tests.iter() {
+            assert!(verify_password(encoded, b"password").unwrap(), "{encoded}");
+            assert!(!verify_password(encoded, b"passwore").unwrap(), "{encoded}");
+        }
+    }
+
+    #[test]
+    fn test_verify_password_rejects_invalid_hashes() {
+        assert!(matches!(
+            verify_password("$md5$rounds=1000$salt$hash", b"password"),
+            Err(PasswordError::UnsupportedAlgorithmError)
+        ));
+        for invalid in [
+            "",
+            "password",
+            "$scrypt$ln=10,r=8$c29tZXNhbHRzb21lc2FsdA$dj05BT7oUTq35qmxXqG",
+            "$scrypt$ln=10,r=8,p=1$c29tZXNhbHRzb21lc2FsdA",
+            "$pbkdf2-sha256$i=1000,l=32$c29tZXNhbHRzb21lc2FsdA$s5LQUeAEZUMuFVrnmF3OMA",
+            "$pbkdf2-sha256$l=32$c29tZXNhbHRzb21lc2FsdA$s5LQUeAEZUMuFVrnmF3OMA",
+            "$pbkdf2-sha256$i=1000,l=32,x=1$c29tZXNhbHRzb21lc2FsdA$s5LQUeAEZUMuFVrnmF3OMNPXs3QWnF8SO/5BXmCj6QQ",
+            "$pbkdf2-sha256$i=1000,x=1$c29tZXNhbHRzb21lc2FsdA$s5LQUeAEZUMuFVrnmF3OMNPXs3QWnF8SO/5BXmCj6QQ",
+        ] {
+            assert!(verify_password(invalid, b"password").is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn test_verify_password_cost_limits() {
+        let salt_and_hash = "c29tZXNhbHRzb21lc2FsdA$dj05BT7oUTq35qmxXqG/pksYG8IJr8uxtvAzbfGjoic";
+        for encoded in [
+            format!("$scrypt$ln=40,r=8,p=1${salt_and_hash}"),
+            format!("$scrypt$ln=63,r=8,p=1${salt_and_hash}"),
+            format!("$scrypt$ln=64,r=8,p=1${salt_and_hash}"),
+            format!("$scrypt$ln=10,r=8,p=17${salt_and_hash}"),
+            format!("$scrypt$ln=10,r=8,p=1048576${salt_and_hash}"),
+            format!("$argon2id$v=19$m=4294967295,t=1,p=1${salt_and_hash}"),
+        ] {
+            assert!(
+                matches!(
+                    verify_password(&encoded, b"password"),
+                    Err(PasswordError::InvalidCostError)
+                ),
+                "{encoded}"
+            );
+        }
+
+        let encoded: String = format!("$scrypt$ln=10,r=8,p=1${salt_and_hash}");
+        let limits = CostLimits::new().with_max_memory(128 * 8 * 1024);
+        assert!(matches!(
+            verify_password_with_limits(&encoded, b"password", &limits),
+            Err(PasswordError::InvalidCostError)
+        ));
+        let limits = limits.with_max_memory(128 * 8 * 1025).with_max_passes(1);
+        assert!(verify_password_with_limits(&encoded, b"password", &limits).unwrap());
+    }
+
+    #[test]
+    fn test_needs_rehash() {
+        let preferred: Argon2 = Argon2::new(Argon2Variant::Argon2id, 64, 1, 1).unwrap();
+        let current: String = preferred
+            .hash_encoded(b"password", b"somesaltsomesalt", 32)
+            .unwrap();
+        let weaker: String = Argon2::new(Argon2Variant::Argon2id, 32, 1, 1)
+            .unwrap()
+            .hash_encoded(b"password", b"somesaltsomesalt", 32)
+            .unwrap();
+        let other_variant: String = Argon2::new(Argon2Variant::Argon2i, 64, 1, 1)
+            .unwrap()
+            .hash_encoded(b"password", b"somesaltsomesalt", 32)
+            .unwrap();
+
+        assert!(!needs_rehash(&current, &preferred).unwrap());
+        assert!(needs_rehash(&weaker, &preferred).unwrap());
+        assert!(needs_rehash(&other_variant, &preferred).unwrap());
+        assert!(needs_rehash("$1$saltsalt$qjXMvbEw8oaL.CzflDtaK/", &preferred).unwrap());
+        assert!(needs_rehash("$argon2id$v=19$m=64", &preferred).unwrap());
+    }
+}
@@ -0,0 +1,251 @@
+use crate::cipher::blowfish::Blowfish;
+use crate::password::PasswordError;
+use crate::secret;
+
+const SALT_LENGTH: usize = 16;
+const HASH_LENGTH: usize = 23;
+const MAX_KEY_LENGTH: usize = 72;
+
+const MAGIC: &[u8; 24] = b"OrpheanBeholderScryDoubt";
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"./ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+
+/*
+The base64 variant of bcrypt, with its own alphabet and without padding.
+*/
+fn encode_base64(bytes: &[u8]) -> String {
+    let mut output: String = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let n: u32 = chunk
+            .iter()
+            .enumerate()
+            .fold(0, |acc, (i, &b)| acc | (b as u32) << (16 - 8 * i));
+        for i in 0..=chunk.len() {
+            output.push(BASE64_ALPHABET[(n >> (18 - 6 * i)) as usize & 0x3f] as char);
+        }
+    }
+    output
+}
+
+fn decode_base64(encoded: &str) -> Result<Vec<u8>, PasswordError> {
+    if encoded.len() % 4 == 1 {
+        return Err(PasswordError::InvalidEncodingError);
+    }
+    let mut output: Vec<u8> = Vec::with_capacity(encoded.len() * 3 / 4);
+    for chunk in encoded.as_bytes().chunks(4) {
+        let mut n: u32 = 0;
+        for (i, c) in chunk.iter().enumerate() {
+            let value = BASE64_ALPHABET
+                .iter()
+                .position(|a| a == c)
+                .ok_or(PasswordError::InvalidEncodingError)?;
+            n |= (value as u32) << (18 - 6 * i);
+        }
+        for i in 0..chunk.len() - 1 {
+            output.push((n >> (16 - 8 * i)) as u8);
+        }
+    }
+    Ok(output)
+}
+
+/*
+The prefixes only record which implementation produced the hash. $2a$
+hashes written by old OpenBSD releases wrapped the password length at
+255 bytes, but passwords are truncated to 72 bytes before that matters,
+so all three versions hash the same way.
+*/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BcryptVersion {
+    V2a,
+    V2b,
+    V2y,
+}
+
+impl BcryptVersion {
+    fn prefix(&self) -> &'static str {
+        match self {
+            BcryptVersion::V2a => "2a",
+            BcryptVersion::V2b => "2b",
+            BcryptVersion::V2y => "2y",
+        }
+    }
+}
+
+/*
+bcrypt from Provos and Mazieres. The password is NUL-terminated and
+truncated to 72 bytes, then used with a 128-bit salt in the EksBlowfish
+setup, whose cost is the base two logarithm of the number of key
+expansion rounds. The resulting state encrypts the magic string 64 times.
+*/
+pub struct Bcrypt {
+    cost: u32,
+    version: BcryptVersion,
+}
+
+impl Bcrypt {
+    pub fn new(cost: u32) -> Result<Self, PasswordError> {
+        if !(4..=31).contains(&cost) {
+            return Err(PasswordError::InvalidCostError);
+        }
+        Ok(Self {
+            cost,
+            version: BcryptVersion::V2b,
+        })
+    }
+
+    pub fn with_version(mut self, version: BcryptVersion) -> Self {
+        self.version = version;
+        self
+    }
+
+    pub fn cost(&self) -> u32 {
+        self.cost
+    }
+
+    fn raw_hash(&self, password: &[u8], salt: &[u8]) -> Vec<u8> {
+        let mut key: Vec<u8> = password.to_vec();
+        key.push(0x00);
+        key.truncate(MAX_KEY_LENGTH);
+
+        let mut blowfish: Blowfish = Blowfish::initial_state();
+        blowfish.expand_key(&key, salt);
+        for _ in 0..1u64 << self.cost {
+            blowfish.expand_key(&key, &[]);
+            blowfish.expand_key(salt, &[]);
+        }
+        secret::zeroize(&mut key);
+
+        let mut output: Vec<u8> = Vec::with_capacity(MAGIC.len());
+        for block in MAGIC.chunks_exact(8) {
+            let mut l: u32 = u32::from_be_bytes(block[..4].try_into().unwrap());
+            let mut r: u32 = u32::from_be_bytes(block[4..].try_into().unwrap());
+            for _ in 0..64 {
+                (l, r) = blowfish.encrypt_words(l, r);
+            }
+            output.extend_from_slice(&l.to_be_bytes());
+            output.extend_from_slice(&r.to_be_bytes());
+        }
+        output.truncate(HASH_LENGTH);
+        output
+    }
+
+    pub fn hash(&self, password: &[u8]) -> Result<String, PasswordError> {
+        let salt: [u8; SALT_LENGTH] = rand::random();
+        self.hash_with_salt(password, &salt)
+    }
+
+    pub fn hash_with_salt(&self, password: &[u8], salt: &[u8]) -> Result<String, PasswordError> {
+        if salt.len() != SALT_LENGTH {
+            return Err(PasswordError::InvalidSaltLengthError);
+        }
+        Ok(format!(
+            "${}${:02}${}{}",
+            self.version.prefix(),
+            self.cost,
+            encode_base64(salt),
+            encode_base64(&self.raw_hash(password, salt))
+        ))
+    }
+
+    pub fn verify(encoded: &str, password: &[u8]) -> Result<bool, PasswordError> {
+        let fields: Vec<&str> = encoded.split('$').collect();
+        let ["", version, cost, salt_and_hash] = fields[..] else {
+            return Err(PasswordError::InvalidEncodingError);
+        };
+        let version: BcryptVersion = match version {
+            "2a" => BcryptVersion::V2a,
+            "2b" => BcryptVersion::V2b,
+            "2y" => BcryptVersion::V2y,
+            _ => return Err(PasswordError::InvalidEncodingError),
+        };
+        if cost.len() != 2 || salt_and_hash.len() != 53 || !salt_and_hash.is_ascii() {
+            return Err(PasswordError::InvalidEncodingError);
+        }
+        let cost: u32 = cost
+            .parse()
+            .map_err(|_| PasswordError::InvalidEncodingError)?;
+
+        let salt: Vec<u8> = decode_base64(&salt_and_hash[..22])?;
+        let hash: Vec<u8> = decode_base64(&salt_and_hash[22..])?;
+        let bcrypt: Bcrypt = Bcrypt::new(cost)?.with_version(version);
+        Ok(secret::ct_eq(&bcrypt.raw_hash(password, &salt), &hash))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bcrypt_on_openwall_suite() {
+        let tests = [
+            (
+                "",
+                "$2a$06$DCq7YPn5Rq63x1Lad4cll.TV4S6ytwfsfvkgY8jIucDrjc8deX1s.",
+            ),
+            (
+                "U*U",
+                "$2a$05$CCCCCCCCCCCCCCCCCCCCC.E5YPO9kmyuRGyh0XouQYb4YMJKvyOeW",
+            ),
+            (
+                "password",
+                "$2b$04$abcdefghijklmnopqrstuughE8Ev8uGFaUgY2cNEySvxngrb/Jzdm",
+            ),
+            (
+                "correct horse battery staple",
+                "$2y$10$N9qo8uLOickgx2ZMRZoMyehtJ/whDdsI.d1w/JqKiOrDw8pCT0JLG",
+            ),
+        ];
+
+        for (password, encoded) in tests {
+            assert!(Bcrypt::verify(encoded, password.as_bytes()).unwrap());
+            assert!(!Bcrypt::verify(encoded, b"wrong password").unwrap());
+        }
+
+        let bcrypt = Bcrypt::new(5).unwrap().with_version(BcryptVersion::V2a);
+        let salt: Vec<u8> = decode_base64("CCCCCCCCCCCCCCCCCCCCC.").unwrap();
+        assert_eq!(
+            bcrypt.hash_with_salt(b"U*U", &salt).unwrap(),
+            "$2a$05$CCCCCCCCCCCCCCCCCCCCC.E5YPO9kmyuRGyh0XouQYb4YMJKvyOeW"
+        );
+    }
+
+    #[test]
+    fn test_bcrypt_truncates_long_passwords() {
+        let password: Vec<u8> = b"0123456789".repeat(8);
+        let encoded = "$2b$04$abcdefghijklmnopqrstuum2G75IXDN/xsgbNa/hCiPSKyIHQd70S";
+        assert!(Bcrypt::verify(encoded, &password).unwrap());
+        assert!(Bcrypt::verify(encoded, &password[..72]).unwrap());
+        assert!(!Bcrypt::verify(encoded, &password[..71]).unwrap());
+    }
+
+    #[test]
+    fn test_bcrypt_random_salt() {
+        let bcrypt = Bcrypt::new(4).unwrap();
+        let first: String = bcrypt.hash(b"password").unwrap();
+        let second: String = bcrypt.hash(b"password").unwrap();
+        assert_ne!(first, second);
+        assert!(Bcrypt::verify(&first, b"password").unwrap());
+        assert!(Bcrypt::verify(&second, b"password").unwrap());
+    }
+
+    #[test]
+    fn test_bcrypt_invalid_parameters() {
+        assert!(Bcrypt::new(3).is_err());
+        assert!(Bcrypt::new(32).is_err());
+        assert!(Bcrypt::new(4)
+            .unwrap()
+            .hash_with_salt(b"", &[0; 15])
+            .is_err());
+        for invalid in [
+            "$2x$05$CCCCCCCCCCCCCCCCCCCCC.E5YPO9kmyuRGyh0XouQYb4YMJKvyOeW",
+            "$2a$5$CCCCCCCCCCCCCCCCCCCCC.E5YPO9kmyuRGyh0XouQYb4YMJKvyOeW",
+            "$2a$05$CCCCCCCCCCCCCCCCCCCCC.E5YPO9kmyuRGyh0XouQYb4YMJKvyOe",
+            "$2a$05$CCCCCCCCCCCCCCCCCCCCC.E5YPO9kmyuRGyh0XouQYb4YMJKvyOe!",
+            "$2a$03$CCCCCCCCCCCCCCCCCCCCC.E5YPO9kmyuRGyh0XouQYb4YMJKvyOeW",
+        ] {
+            assert!(Bcrypt::verify(invalid, b"U*U").is_err());
+        }
+    }
+}
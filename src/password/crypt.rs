@@ -0,0 +1,474 @@
+use crate::hash::md5::MD5;
+use crate::hash::sha256::SHA256;
+use crate::hash::sha512::SHA512;
+use crate::hash::Message;
+use crate::password::PasswordError;
+use crate::secret;
+
+const MD5_ROUNDS: u32 = 1000;
+const DEFAULT_ROUNDS: u32 = 5000;
+const MIN_ROUNDS: u32 = 1000;
+const MAX_ROUNDS: u32 = 999_999_999;
+
+/*
+Longest password accepted, the same limit as libxcrypt. The SHA-based
+algorithms hash the password repeated as many times as it is long, so
+without a limit the memory and time needed grow with the square of the
+password length.
+*/
+const MAX_PASSWORD_LENGTH: usize = 512;
+
+const ALPHABET: &[u8; 64] = b"./0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/*
+Order in which the digest bytes are packed into groups of three before
+being encoded, each group least significant character first. The last
+entry only fills as many characters as the remaining bits need.
+*/
+const MD5_GROUPS: [[usize; 3]; 5] = [[0, 6, 12], [1, 7, 13], [2, 8, 14], [3, 9, 15], [4, 10, 5]];
+const MD5_TAIL: ([usize; 3], usize) = ([16, 16, 11], 2);
+
+const SHA256_GROUPS: [[usize; 3]; 10] = [
+    [0, 10, 20],
+    [21, 1, 11],
+    [12, 22, 2],
+    [3, 13, 23],
+    [24, 4, 14],
+    [15, 25, 5],
+    [6, 16, 26],
+    [27, 7, 17],
+    [18, 28, 8],
+    [9, 19, 29],
+];
+const SHA256_TAIL: ([usize; 3], usize) = ([32, 31, 30], 3);
+
+const SHA512_GROUPS: [[usize; 3]; 21] = [
+    [0, 21, 42],
+    [22, 43, 1],
+    [44, 2, 23],
+    [3, 24, 45],
+    [25, 46, 4],
+    [47, 5, 26],
+    [6, 27, 48],
+    [28, 49, 7],
+    [50, 8, 29],
+    [9, 30, 51],
+    [31, 52, 10],
+    [53, 11, 32],
+    [12, 33, 54],
+    [34, 55, 13],
+    [56, 14, 35],
+    [15, 36, 57],
+    [37, 58, 16],
+    [59, 17, 38],
+    [18, 39, 60],
+    [40, 61, 19],
+    [62, 20, 41],
+];
+const SHA512_TAIL: ([usize; 3], usize) = ([64, 64, 63], 2);
+
+fn encode(digest: &[u8], groups: &[[usize; 3]], tail: ([usize; 3], usize)) -> String {
+    let byte = |i: usize| *digest.get(i).unwrap_or(&0) as u32;
+    let mut output: String = String::new();
+    let groups = groups
+        .iter()
+        .map(|group| (group, 4))
+        .chain([(&tail.0, tail.1)]);
+    for ([a, b, c], length) in groups {
+        let mut w: u32 = byte(*a) << 16 | byte(*b) << 8 | byte(*c);
+        for _ in 0..length {
+            output.push(ALPHABET[(w & 0x3f) as usize] as char);
+            w >>= 6;
+        }
+    }
+    output
+}
+
+/* Repeats a digest over the given length, as done for the password and salt. */
+fn repeat(digest: &[u8], length: usize) -> Vec<u8> {
+    digest.iter().cycle().take(length).copied().collect()
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CryptAlgorithm {
+    Md5,
+    Sha256,
+    Sha512,
+}
+
+impl CryptAlgorithm {
+    fn id(&self) -> &'static str {
+        match self {
+            CryptAlgorithm::Md5 => "1",
+            CryptAlgorithm::Sha256 => "5",
+            CryptAlgorithm::Sha512 => "6",
+        }
+    }
+
+    fn max_salt_length(&self) -> usize {
+        match self {
+            CryptAlgorithm::Md5 => 8,
+            _ => 16,
+        }
+    }
+
+    fn digest(&self, input: &[u8]) -> Vec<u8> {
+        let input: Message = Message::from_slice(input);
+        let digest = match self {
+            CryptAlgorithm::Md5 => MD5.hash(&input),
+            CryptAlgorithm::Sha256 => SHA256.hash(&input),
+            CryptAlgorithm::Sha512 => SHA512.hash(&input),
+        };
+        digest.to_slice().to_vec()
+    }
+}
+
+/*
+The MD5-based crypt of FreeBSD ($1$) and the SHA-256 and SHA-512 based
+crypt of Ulrich Drepper ($5$ and $6$), as found in /etc/shadow. The
+SHA-based algorithms take a number of rounds, which is only written in
+the output when it was chosen explicitly, and clamped to the range
+allowed by the specification when read from a hash.
+*/
+pub struct Crypt {
+    algorithm: CryptAlgorithm,
+    rounds: Option<u32>,
+}
+
+impl Crypt {
+    pub fn new(algorithm: CryptAlgorithm) -> Self {
+        Self {
+            algorithm,
+            rounds: None,
+        }
+    }
+
+    pub fn with_rounds(mut self, rounds: u32) -> Result<Self, PasswordError> {
+        if self.algorithm == CryptAlgorithm::Md5 || !(MIN_ROUNDS..=MAX_ROUNDS).contains(&rounds) {
+            return Err(PasswordError::InvalidCostError);
+        }
+        self.rounds = Some(rounds);
+        Ok(self)
+    }
+
+    fn md5_crypt(&self, password: &[u8], salt: &[u8]) -> Vec<u8> {
+        let algorithm: CryptAlgorithm = self.algorithm;
+        let b: Vec<u8> = algorithm.digest(&[password, salt, password].concat());
+
+        let mut a: Vec<u8> = [password, b"$1$", salt].concat();
+        a.extend_from_slice(&repeat(&b, password.len()));
+        let mut i: usize = password.len();
+        while i > 0 {
+            match i & 1 {
+                1 => a.push(0x00),
+                _ => a.push(*password.first().unwrap_or(&0)),
+            }
+            i >>= 1;
+        }
+        let mut c: Vec<u8> = algorithm.digest(&a);
+        secret::zeroize(&mut a);
+
+        for i in 0..MD5_ROUNDS {
+            let mut input: Vec<u8> = vec![];
+            input.extend_from_slice(if i & 1 == 1 { password } else { &c });
+            if i % 3 != 0 {
+                input.extend_from_slice(salt);
+            }
+            if i % 7 != 0 {
+                input.extend_from_slice(password);
+            }
+            input.extend_from_slice(if i & 1 == 1 { &c } else { password });
+            c = algorithm.digest(&input);
+            secret::zeroize(&mut input);
+        }
+        c
+    }
+
+    fn sha_crypt(&self, password: &[u8], salt: &[u8]) -> Vec<u8> {
+        let algorithm: CryptAlgorithm = self.algorithm;
+        let b: Vec<u8> = algorithm.digest(&[password, salt, password].concat());
+
+        let mut a: Vec<u8> = [password, salt].concat();
+        a.extend_from_slice(&repeat(&b, password.len()));
+        let mut i: usize = password.len();
+        while i > 0 {
+            match i & 1 {
+                1 => a.extend_from_slice(&b),
+                _ => a.extend_from_slice(password),
+            }
+            i >>= 1;
+        }
+        let mut c: Vec<u8> = algorithm.digest(&a);
+        secret::zeroize(&mut a);
+
+        let mut repeated: Vec<u8> = password.repeat(password.len());
+        let dp: Vec<u8> = algorithm.digest(&repeated);
+        secret::zeroize(&mut repeated);
+        let mut p: Vec<u8> = repeat(&dp, password.len());
+        let ds: Vec<u8> = algorithm.digest(&salt.repeat(16 + c[0] as usize));
+        let s: Vec<u8> = repeat(&ds, salt.len());
+
+        for i in 0..self.rounds.unwrap_or(DEFAULT_ROUNDS) {
+            let mut input: Vec<u8> = vec![];
+            input.extend_from_slice(if i & 1 == 1 { &p } else { &c });
+            if i % 3 != 0 {
+                input.extend_from_slice(&s);
+            }
+            if i % 7 != 0 {
+                input.extend_from_slice(&p);
+            }
+            input.extend_from_slice(if i & 1 == 1 { &c } else { &p });
+            c = algorithm.digest(&input);
+            secret::zeroize(&mut input);
+        }
+        secret::zeroize(&mut p);
+        c
+    }
+
+    pub fn hash(&self, password: &[u8]) -> Result<String, PasswordError> {
+        let salt: String = (0..self.algorithm.max_salt_length())
+            .map(|_| ALPHABET[rand::random::<usize>() % ALPHABET.len()] as char)
+            .collect();
+        self.hash_with_salt(password, &salt)
+    }
+
+    pub fn hash_with_salt(&self, password: &[u8], salt: &str) -> Result<String, PasswordError> {
+        if salt.contains(['$', ':', '\n']) {
+            return Err(PasswordError::InvalidEncodingError);
+        }
+        if password.len() > MAX_PASSWORD_LENGTH {
+            return Err(PasswordError::InvalidPasswordLengthError);
+        }
+        let salt: &[u8] = &salt.as_bytes()[..salt.len().min(self.algorithm.max_salt_length())];
+
+        let (digest, groups, tail): (Vec<u8>, &[[usize; 3]], _) = match self.algorithm {
+            CryptAlgorithm::Md5 => (self.md5_crypt(password, salt), &MD5_GROUPS, MD5_TAIL),
+            CryptAlgorithm::Sha256 => (self.sha_crypt(password, salt), &SHA256_GROUPS, SHA256_TAIL),
+            CryptAlgorithm::Sha512 => (self.sha_crypt(password, salt), &SHA512_GROUPS, SHA512_TAIL),
+        };
+        let rounds: String = match self.rounds {
+            Some(rounds) => format!("rounds={rounds}$"),
+            None => String::new(),
+        };
+        Ok(format!(
+            "${}${}{}${}",
+            self.algorithm.id(),
+            rounds,
+            String::from_utf8_lossy(salt),
+            encode(&digest, groups, tail)
+        ))
+    }
+
+    pub fn verify(encoded: &str, password: &[u8]) -> Result<bool, PasswordError> {
+        let fields: Vec<&str> = encoded.split('$').collect();
+        let (id, rounds, salt, hash) = match fields[..] {
+            ["", id, rounds, salt, hash] if rounds.starts_with("rounds=") => {
+                (id, Some(rounds), salt, hash)
+            }
+            ["", id, salt, hash] => (id, None, salt, hash),
+            _ => return Err(PasswordError::InvalidEncodingError),
+        };
+        let algorithm: CryptAlgorithm = match id {
+            "1" => CryptAlgorithm::Md5,
+            "5" => CryptAlgorithm::Sha256,
+            "6" => CryptAlgorithm::Sha512,
+            _ => return Err(PasswordError::InvalidEncodingError),
+        };
+
+        let mut crypt: Crypt = Crypt::new(algorithm);
+        if let Some(rounds) = rounds {
+            let rounds: u32 = rounds["rounds=".len()..]
+                .parse()
+                .map_err(|_| PasswordError::InvalidEncodingError)?;
+            crypt = crypt.with_rounds(rounds.clamp(MIN_ROUNDS, MAX_ROUNDS))?;
+        }
+        /*
+        Only the hash is compared, as the rounds may have been clamped and
+        would then be written differently than in the encoded string.
+        */
+        let computed: String = crypt.hash_with_salt(password, salt)?;
+        let computed_hash: &str = computed.rsplit('$').next().unwrap();
+        Ok(secret::ct_eq(computed_hash.as_bytes(), hash.as_bytes()))
+    }
+}
+
+/*
+One line of /etc/shadow. The password field may hold a crypt hash, be
+empty when no password is needed, or start with ! or * when the account
+is locked, in which case no password matches.
+*/
+pub struct ShadowEntry {
+    username: String,
+    hash: String,
+}
+
+impl ShadowEntry {
+    pub fn parse(line: &str) -> Result<Self, PasswordError> {
+        let fields: Vec<&str> = line.trim_end_matches('\n').split(':').collect();
+        if fields.len() != 9 || fields[0].is_empty() {
+            return Err(PasswordError::InvalidEncodingError);
+        }
+        Ok(Self {
+            username: fields[0].to_string(),
+            hash: fields[1].to_string(),
+        })
+    }
+
+    pub fn username(&self) -> &str {
+        &self.username
+    }
+
+    pub fn hash(&self) -> &str {
+        &self.hash
+    }
+
+    pub fn is_locked(&self) -> bool {
+        self.hash.starts_with(['!', '*'])
+    }
+
+    pub fn verify(&self, password: &[u8]) -> Result<bool, PasswordError> {
+        if self.is_locked() {
+            return Ok(false);
+        }
+        if self.hash.is_empty() {
+            return Ok(password.is_empty());
+        }
+        Crypt::verify(&self.hash, password)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_md5_crypt() {
+        let tests = [
+            ("password", "$1$saltsalt$qjXMvbEw8oaL.CzflDtaK/"),
+            ("", "$1$abc$Or2rbeUYTvt12aiVzMuS/."),
+            (
+                "a much longer password that spans several md5 blocks",
+                "$1$abcdefgh$fxbyL1iRu94mpev3ycyI20",
+            ),
+        ];
+
+        for (password, encoded) in tests {
+            assert!(Crypt::verify(encoded, password.as_bytes()).unwrap());
+            assert!(!Crypt::verify(encoded, b"wrong").unwrap());
+        }
+        assert_eq!(
+            Crypt::new(CryptAlgorithm::Md5)
+                .hash_with_salt(
+                    b"a much longer password that spans several md5 blocks",
+                    "abcdefghijkl"
+                )
+                .unwrap(),
+            "$1$abcdefgh$fxbyL1iRu94mpev3ycyI20"
+        );
+    }
+
+    #[test]
+    fn test_sha256_crypt_on_drepper_suite() {
+        let tests = [
+            (
+                "Hello world!",
+                "$5$saltstring$5B8vYYiY.CVt1RlTTf8KbXBH3hsxY/GNooZaBBGWEc5",
+            ),
+            (
+                "Hello world!",
+                "$5$rounds=10000$saltstringsaltst$3xv.VbSHBb41AL9AvLeujZkZRBAwqFMz2.opqey6IcA",
+            ),
+            (
+                "the minimum number is still observed",
+                "$5$rounds=1000$roundstoolow$yfvwcWrQ8l/K0DAWyuPMDNHpIVlTQebY9l/gL972bIC",
+            ),
+            (
+                "the minimum number is still observed",
+                "$5$rounds=10$roundstoolow$yfvwcWrQ8l/K0DAWyuPMDNHpIVlTQebY9l/gL972bIC",
+            ),
+        ];
+
+        for (password, encoded) in tests {
+            assert!(Crypt::verify(encoded, password.as_bytes()).unwrap());
+        }
+        assert_eq!(
+            Crypt::new(CryptAlgorithm::Sha256)
+                .with_rounds(10000)
+                .unwrap()
+                .hash_with_salt(b"Hello world!", "saltstringsaltstring")
+                .unwrap(),
+            "$5$rounds=10000$saltstringsaltst$3xv.VbSHBb41AL9AvLeujZkZRBAwqFMz2.opqey6IcA"
+        );
+    }
+
+    #[test]
+    fn test_sha512_crypt_on_drepper_suite() {
+        let tests = [
+            ("Hello world!", "$6$saltstring$svn8UoSVapNtMuq1ukKS4tPQd8iKwSMHWjl/O817G3uBnIFNjnQJuesI68u4OTLiBFdcbYEdFCoEOfaS35inz1"),
+            ("Hello world!", "$6$rounds=10000$saltstringsaltst$OW1/O6BYHV6BcXZu8QVeXbDWra3Oeqh0sbHbbMCVNSnCM/UrjmM0Dp8vOuZeHBy/YTBmSK6H9qs/y3RnOaw5v."),
+            ("a very much longer text to encrypt.  This one even stretches over morethan one line.", "$6$rounds=1400$anotherlongsalts$POfYwTEok97VWcjxIiSOjiykti.o/pQs.wPvMxQ6Fm7I6IoYN3CmLs66x9t0oSwbtEW7o7UmJEiDwGqd8p4ur1"),
+            ("This is just a test", "$6$rounds=5000$toolongsaltstrin$lQ8jolhgVRVhY4b5pZKaysCLi0QBxGoNeKQzQ3glMhwllF7oGDZxUhx1yxdYcz/e1JSbq3y6JMxxl8audkUEm0"),
+        ];
+
+        for (password, encoded) in tests {
+            assert!(Crypt::verify(encoded, password.as_bytes()).unwrap());
+            assert!(!Crypt::verify(encoded, b"wrong").unwrap());
+        }
+        let crypt = Crypt::new(CryptAlgorithm::Sha512);
+        let encoded: String = crypt.hash(b"secret").unwrap();
+        assert!(encoded.starts_with("$6$"));
+        assert!(Crypt::verify(&encoded, b"secret").unwrap());
+    }
+
+    #[test]
+    fn test_shadow_entry() {
+        let entry = ShadowEntry::parse(
+            "alice:$5$saltstring$5B8vYYiY.CVt1RlTTf8KbXBH3hsxY/GNooZaBBGWEc5:19000:0:99999:7:::\n",
+        )
+        .unwrap();
+        assert_eq!(entry.username(), "alice");
+        assert!(entry.verify(b"Hello world!").unwrap());
+        assert!(!entry.verify(b"hello world!").unwrap());
+
+        let locked = ShadowEntry::parse(
+            "bob:!$5$saltstring$5B8vYYiY.CVt1RlTTf8KbXBH3hsxY/GNooZaBBGWEc5:19000:0:99999:7:::",
+        )
+        .unwrap();
+        assert!(locked.is_locked());
+        assert!(!locked.verify(b"Hello world!").unwrap());
+        assert!(!ShadowEntry::parse("daemon:*:19000:0:99999:7:::")
+            .unwrap()
+            .verify(b"")
+            .unwrap());
+        assert!(ShadowEntry::parse("guest::19000:0:99999:7:::")
+            .unwrap()
+            .verify(b"")
+            .unwrap());
+
+        assert!(ShadowEntry::parse("alice:$6$salt$hash").is_err());
+        assert!(Crypt::verify("$7$salt$hash", b"").is_err());
+        assert!(Crypt::verify("$5$rounds=abc$salt$hash", b"").is_err());
+        assert!(Crypt::new(CryptAlgorithm::Sha256).with_rounds(999).is_err());
+        assert!(Crypt::new(CryptAlgorithm::Md5).with_rounds(5000).is_err());
+    }
+
+    #[test]
+    fn test_crypt_rejects_long_passwords() {
+        let password: Vec<u8> = vec![b'a'; MAX_PASSWORD_LENGTH];
+        let crypt = Crypt::new(CryptAlgorithm::Sha512)
+            .with_rounds(1000)
+            .unwrap();
+        let encoded: String = crypt.hash(&password).unwrap();
+        assert!(Crypt::verify(&encoded, &password).unwrap());
+
+        let password: Vec<u8> = vec![b'a'; 64 * 1024];
+        assert!(matches!(
+            crypt.hash(&password),
+            Err(PasswordError::InvalidPasswordLengthError)
+        ));
+        assert!(matches!(
+            Crypt::verify(&encoded, &password),
+            Err(PasswordError::InvalidPasswordLengthError)
+        ));
+    }
+}
@@ -0,0 +1,276 @@
+use std::fmt;
+
+use crate::password::PasswordError;
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+const MAX_NAME_LENGTH: usize = 32;
+
+/* Standard base64 without padding, as used for salts and hashes in PHC strings. */
+pub(crate) fn encode_base64(bytes: &[u8]) -> String {
+    let mut output: String = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let n: u32 = chunk
+            .iter()
+            .enumerate()
+            .fold(0, |acc, (i, &b)| acc | (b as u32) << (16 - 8 * i));
+        for i in 0..=chunk.len() {
+            output.push(BASE64_ALPHABET[(n >> (18 - 6 * i)) as usize & 0x3f] as char);
+        }
+    }
+    output
+}
+
+pub(crate) fn decode_base64(encoded: &str) -> Result<Vec<u8>, PasswordError> {
+    if encoded.len() % 4 == 1 {
+        return Err(PasswordError::InvalidEncodingError);
+    }
+    let mut output: Vec<u8> = Vec::with_capacity(encoded.len() * 3 / 4);
+    for chunk in encoded.as_bytes().chunks(4) {
+        let mut n: u32 = 0;
+        for (i, c) in chunk.iter().enumerate() {
+            let value = BASE64_ALPHABET
+                .iter()
+                .position(|a| a == c)
+                .ok_or(PasswordError::InvalidEncodingError)?;
+            n |= (value as u32) << (18 - 6 * i);
+        }
+        /* Bits of the last character that fall past the final byte must be zero. */
+        let unused_bits: usize = 24 - 8 * (chunk.len() - 1);
+        if n & ((1 << unused_bits) - 1) != 0 {
+            return Err(PasswordError::InvalidEncodingError);
+        }
+        for i in 0..chunk.len() - 1 {
+            output.push((n >> (16 - 8 * i)) as u8);
+        }
+    }
+    Ok(output)
+}
+
+fn is_valid_name(name: &str) -> bool {
+    !name.is_empty()
+        && name.len() <= MAX_NAME_LENGTH
+        && name
+            .bytes()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == b'-')
+}
+
+fn is_valid_value(value: &str) -> bool {
+    !value.is_empty()
+        && value
+            .bytes()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, b'/' | b'+' | b'.' | b'-'))
+}
+
+/*
+A password hash in the PHC string format,
+$<id>[$v=<version>][$<param>=<value>(,<param>=<value>)*][$<salt>[$<hash>]],
+where the salt and the hash are encoded in unpadded base64. Parameters
+keep the order in which they were given.
+*/
+#[derive(Clone, Debug, PartialEq)]
+pub struct PhcString {
+    id: String,
+    version: Option<u32>,
+    params: Vec<(String, String)>,
+    salt: Option<Vec<u8>>,
+    hash: Option<Vec<u8>>,
+}
+
+impl PhcString {
+    pub fn new(id: &str) -> Result<Self, PasswordError> {
+        if !is_valid_name(id) {
+            return Err(PasswordError::InvalidEncodingError);
+        }
+        Ok(Self {
+            id: id.to_string(),
+            version: None,
+            params: vec![],
+            salt: None,
+            hash: None,
+        })
+    }
+
+    pub fn with_version(mut self, version: u32) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    pub fn with_param(mut self, name: &str, value: &str) -> Result<Self, PasswordError> {
+        if !is_valid_name(name) || !is_valid_value(value) || self.param(name).is_some() {
+            return Err(PasswordError::InvalidEncodingError);
+        }
+        self.params.push((name.to_string(), value.to_string()));
+        Ok(self)
+    }
+
+    pub fn with_salt(mut self, salt: &[u8]) -> Self {
+        self.salt = Some(salt.to_vec());
+        self
+    }
+
+    pub fn with_hash(mut self, hash: &[u8]) -> Self {
+        self.hash = Some(hash.to_vec());
+        self
+    }
+
+    pub fn parse(encoded: &str) -> Result<Self, PasswordError> {
+        let mut fields = match encoded.strip_prefix('$') {
+            Some(fields) => fields.split('$').peekable(),
+            None => return Err(PasswordError::InvalidEncodingError),
+        };
+        let mut phc: PhcString = PhcString::new(fields.next().unwrap())?;
+
+        if let Some(version) = fields.peek().and_then(|field| field.strip_prefix("v=")) {
+            let version: u32 = version
+                .parse()
+                .map_err(|_| PasswordError::InvalidEncodingError)?;
+            phc = phc.with_version(version);
+            fields.next();
+        }
+        if fields.peek().is_some_and(|field| field.contains('=')) {
+            for param in fields.next().unwrap().split(',') {
+                let (name, value) = param
+                    .split_once('=')
+                    .ok_or(PasswordError::InvalidEncodingError)?;
+                phc = phc.with_param(name, value)?;
+            }
+        }
+        if let Some(salt) = fields.next() {
+            phc = phc.with_salt(&decode_base64(salt)?);
+        }
+        if let Some(hash) = fields.next() {
+            phc = phc.with_hash(&decode_base64(hash)?);
+        }
+        if fields.next().is_some() {
+            return Err(PasswordError::InvalidEncodingError);
+        }
+        Ok(phc)
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn version(&self) -> Option<u32> {
+        self.version
+    }
+
+    pub fn params(&self) -> &[(String, String)] {
+        &self.params
+    }
+
+    pub fn param(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(param, _)| param == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn salt(&self) -> Option<&[u8]> {
+        self.salt.as_deref()
+    }
+
+    pub fn hash(&self) -> Option<&[u8]> {
+        self.hash.as_deref()
+    }
+}
+
+impl fmt::Display for PhcString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "${}", self.id)?;
+        if let Some(version) = self.version {
+            write!(f, "$v={version}")?;
+        }
+        if !self.params.is_empty() {
+            let params: Vec<String> = self
+                .params
+                .iter()
+                .map(|(name, value)| format!("{name}={value}"))
+                .collect();
+            write!(f, "${}", params.join(","))?;
+        }
+        if self.salt.is_some() || self.hash.is_some() {
+            write!(f, "${}", encode_base64(self.salt().unwrap_or_default()))?;
+        }
+        if let Some(hash) = self.hash() {
+            write!(f, "${}", encode_base64(hash))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_phc_string_round_trip() {
+        let tests = [
+            "$argon2id$v=19$m=65536,t=2,p=1$c29tZXNhbHQ$CTFhFdXPJO1aFaMaO6Mm5c8y7cJHAph8ArZWb2GRPPc",
+            "$scrypt$ln=10,r=8,p=1$c29tZXNhbHRzb21lc2FsdA$dj05BT7oUTq35qmxXqG/pksYG8IJr8uxtvAzbfGjoic",
+            "$pbkdf2-sha256$i=1000,l=32$c29tZXNhbHRzb21lc2FsdA",
+            "$argon2i$v=19",
+            "$custom",
+        ];
+
+        for encoded in tests {
+            assert_eq!(PhcString::parse(encoded).unwrap().to_string(), encoded);
+        }
+
+        let phc = PhcString::parse(tests[0]).unwrap();
+        assert_eq!(phc.id(), "argon2id");
+        assert_eq!(phc.version(), Some(19));
+        assert_eq!(phc.param("m"), Some("65536"));
+        assert_eq!(phc.param("x"), None);
+        assert_eq!(phc.salt(), Some(&b"somesalt"[..]));
+        assert_eq!(phc.hash().unwrap().len(), 32);
+
+        let built = PhcString::new("argon2id")
+            .unwrap()
+            .with_version(19)
+            .with_param("m", "65536")
+            .unwrap()
+            .with_param("t", "2")
+            .unwrap()
+            .with_param("p", "1")
+            .unwrap()
+            .with_salt(b"somesalt")
+            .with_hash(phc.hash().unwrap());
+        assert_eq!(built, phc);
+    }
+
+    #[test]
+    fn test_base64_is_canonical() {
+        assert_eq!(decode_base64("QQ").unwrap(), b"A");
+        assert_eq!(decode_base64("QUI").unwrap(), b"AB");
+        for invalid in ["QR", "QUJ", "Q", "QQ=="] {
+            assert!(decode_base64(invalid).is_err(), "{invalid}");
+        }
+        for length in 0..8 {
+            let bytes: Vec<u8> = (0..length).map(|i| 0xf0 | i).collect();
+            assert_eq!(decode_base64(&encode_base64(&bytes)).unwrap(), bytes);
+        }
+    }
+
+    #[test]
+    fn test_invalid_phc_strings() {
+        for invalid in [
+            "",
+            "argon2id$v=19",
+            "$",
+            "$Argon2id",
+            "$argon2id$v=x",
+            "$argon2id$m=1,m=2",
+            "$argon2id$m=",
+            "$argon2id$m=1$c29tZXNhbHQ$aGFzaA$extra",
+            "$argon2id$m=1$c29tZXNhbHQ!",
+            "$aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+            "$pbkdf2-sha256$i=1000$c29tZXNhbHRzb21lc2FsdB",
+            "$pbkdf2-sha256$i=1000$c29tZXNhbHRzb21lc2FsdA$s5LQUeAEZUMuFVrnmF3OMNPXs3QWnF8SO/5BXmCj6QR",
+        ] {
+            assert!(PhcString::parse(invalid).is_err(), "{invalid}");
+        }
+    }
+}
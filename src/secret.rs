@@ -0,0 +1,193 @@
+use std::sync::atomic::{compiler_fence, Ordering};
+use std::{error, fmt, hint, ptr};
+
+#[derive(Debug)]
+pub enum SecretError {
+    MemoryLockError,
+}
+
+impl fmt::Display for SecretError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SecretError::MemoryLockError => write!(f, "Memory could not be locked"),
+        }
+    }
+}
+
+impl error::Error for SecretError {}
+
+/*
+Overwrites the whole allocation of a vector with zeros, including the
+spare capacity that may still hold older contents. The writes are
+volatile and followed by a fence so the compiler cannot drop them as dead
+stores. Copies left behind by earlier reallocations are not reachable
+from here, so secrets should be sized up front where possible.
+*/
+pub(crate) fn zeroize<T: Copy + Default>(buffer: &mut Vec<T>) {
+    buffer.clear();
+    let start: *mut T = buffer.as_mut_ptr();
+    for i in 0..buffer.capacity() {
+        unsafe { ptr::write_volatile(start.add(i), T::default()) };
+    }
+    compiler_fence(Ordering::SeqCst);
+}
+
+/*
+Same as zeroize for memory that is not owned by a vector, such as a key
+schedule stored in a fixed-size array.
+*/
+pub(crate) fn zeroize_slice<T: Copy + Default>(buffer: &mut [T]) {
+    for x in buffer.iter_mut() {
+        unsafe { ptr::write_volatile(x, T::default()) };
+    }
+    compiler_fence(Ordering::SeqCst);
+}
+
+/* Same as zeroize for a single inline value, such as a word of key material. */
+pub(crate) fn zeroize_value<T: Copy + Default>(value: &mut T) {
+    zeroize_slice(std::slice::from_mut(value));
+}
+
+/*
+Compares two byte strings in time that only depends on their lengths,
+so that the position of the first difference is not leaked.
+*/
+pub(crate) fn ct_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let difference: u8 = a
+        .iter()
+        .zip(b)
+        .fold(0, |acc, (x, y)| hint::black_box(acc | (x ^ y)));
+    difference == 0
+}
+
+/*
+Fixed-size buffer for key material that is locked in memory with mlock,
+so that it is never written to swap, and wiped before it is unlocked and
+freed. The buffer never grows, so no stale copies are left behind.
+Available on Linux with the mlock feature.
+*/
+#[cfg(all(feature = "mlock", target_os = "linux"))]
+pub struct SecretBuffer {
+    buffer: Vec<u8>,
+}
+
+#[cfg(all(feature = "mlock", target_os = "linux"))]
+impl SecretBuffer {
+    pub fn new(length: usize) -> Result<Self, SecretError> {
+        let buffer: Vec<u8> = vec![0; length];
+        if length > 0 && unsafe { libc::mlock(buffer.as_ptr().cast(), length) } != 0 {
+            return Err(SecretError::MemoryLockError);
+        }
+        Ok(Self { buffer })
+    }
+
+    pub fn from_slice(secret: &[u8]) -> Result<Self, SecretError> {
+        let mut buffer: Self = Self::new(secret.len())?;
+        buffer.as_mut_slice().copy_from_slice(secret);
+        Ok(buffer)
+    }
+
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    pub fn to_slice(&self) -> &[u8] {
+        &self.buffer
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        &mut self.buffer
+    }
+}
+
+#[cfg(all(feature = "mlock", target_os = "linux"))]
+impl Drop for SecretBuffer {
+    fn drop(&mut self) {
+        let length: usize = self.buffer.len();
+        zeroize(&mut self.buffer);
+        if length > 0 {
+            unsafe { libc::munlock(self.buffer.as_ptr().cast(), length) };
+        }
+    }
+}
+
+#[cfg(all(feature = "mlock", target_os = "linux"))]
+impl fmt::Debug for SecretBuffer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SecretBuffer {{ length: {} }}", self.buffer.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hash::Digest;
+
+    #[test]
+    fn test_zeroize() {
+        let mut buffer: Vec<u8> = Vec::with_capacity(32);
+        buffer.extend_from_slice(&[0xaa; 32]);
+        buffer.truncate(16);
+        zeroize(&mut buffer);
+        assert!(buffer.is_empty());
+        let spare: &[u8] =
+            unsafe { std::slice::from_raw_parts(buffer.as_ptr(), buffer.capacity()) };
+        assert!(spare.iter().all(|&byte| byte == 0));
+    }
+
+    #[test]
+    fn test_zeroize_slice_and_value() {
+        let mut schedule: [[u32; 4]; 2] = [[0xdeadbeef; 4]; 2];
+        zeroize_slice(schedule.as_flattened_mut());
+        assert_eq!(schedule, [[0; 4]; 2]);
+
+        let mut key: u128 = u128::MAX;
+        zeroize_value(&mut key);
+        assert_eq!(key, 0);
+
+        let mut words: [u64; 2] = [u64::MAX; 2];
+        zeroize_value(&mut words);
+        assert_eq!(words, [0; 2]);
+    }
+
+    #[test]
+    fn test_ct_eq() {
+        assert!(ct_eq(b"", b""));
+        assert!(ct_eq(b"abc", b"abc"));
+        assert!(!ct_eq(b"abc", b"abd"));
+        assert!(!ct_eq(b"abc", b"ab"));
+    }
+
+    #[test]
+    fn test_digest_verify() {
+        let digest = Digest::from_u8(&[0xde, 0xad, 0xbe, 0xef]);
+        assert!(digest.verify(&[0xde, 0xad, 0xbe, 0xef]));
+        assert!(!digest.verify(&[0xde, 0xad, 0xbe]));
+        assert!(digest.verify_hex("deadbeef").unwrap());
+        assert!(!digest.verify_hex("deadbeee").unwrap());
+        assert!(digest.verify_hex("xyz").is_err());
+        assert!(digest.verify_hex("+e+dbeef").is_err());
+        assert!(digest.verify_hex("aébeef0").is_err());
+        assert!(digest.verify_hex("ééééé").is_err());
+        assert!(digest == Digest::from_u8(&[0xde, 0xad, 0xbe, 0xef]));
+        assert!(digest != Digest::from_u8(&[0; 4]));
+    }
+
+    #[cfg(all(feature = "mlock", target_os = "linux"))]
+    #[test]
+    fn test_secret_buffer() {
+        let mut secret = SecretBuffer::from_slice(b"secret key").unwrap();
+        assert_eq!(secret.to_slice(), b"secret key");
+        secret.as_mut_slice()[0] = b'S';
+        assert_eq!(secret.to_slice(), b"Secret key");
+        assert_eq!(secret.len(), 10);
+        assert!(SecretBuffer::new(0).unwrap().is_empty());
+    }
+}